@@ -5,14 +5,202 @@ use byteorder::{BigEndian, ReadBytesExt};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 
-use crate::api::{SECRET, USER_AGENT};
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::api::{API_PASSWORD, API_USERNAME, SECRET, USER_AGENT};
 
 type HmacSha256 = Hmac<Sha256>;
 
-#[tracing::instrument(level = "debug")]
-fn calculate_hmac(s: &str) -> String {
+/// Environment variable selecting the identity preset (currently only `android`).
+pub const APP_PRESET_ENV: &str = "HOMEGATE_APP_PRESET";
+/// Environment variable overriding [`AppIdentity::platform`].
+pub const APP_PLATFORM_ENV: &str = "HOMEGATE_APP_PLATFORM";
+/// Environment variable overriding [`AppIdentity::version`].
+pub const APP_VERSION_ENV: &str = "HOMEGATE_APP_VERSION";
+/// Environment variable overriding [`AppIdentity::build`].
+pub const APP_BUILD_ENV: &str = "HOMEGATE_APP_BUILD";
+/// Environment variable overriding [`AppIdentity::sdk_level`].
+pub const APP_SDK_LEVEL_ENV: &str = "HOMEGATE_APP_SDK_LEVEL";
+/// Environment variable overriding [`AppIdentity::user_agent`].
+pub const APP_USER_AGENT_ENV: &str = "HOMEGATE_APP_USER_AGENT";
+/// Environment variable overriding [`AppIdentity::secret`], as a plain string.
+pub const APP_SECRET_ENV: &str = "HOMEGATE_APP_SECRET";
+/// Environment variable overriding [`AppIdentity::username`].
+pub const API_USERNAME_ENV: &str = "HOMEGATE_API_USERNAME";
+/// Environment variable overriding [`AppIdentity::password`].
+pub const API_PASSWORD_ENV: &str = "HOMEGATE_API_PASSWORD";
+
+/// Identity of the mobile app the client impersonates.
+///
+/// The API authenticates requests with HTTP Basic credentials, an `X-App-Version`
+/// header built from the platform, version, build and SDK level, a `User-Agent` and
+/// a time-based `X-App-Id` signed with the app's HMAC secret. When the mobile app
+/// updates, these values can be overridden without waiting for a crate release.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::app_id::AppIdentity;
+///
+/// let identity = AppIdentity {
+///     version: "12.7.0".to_string(),
+///     build: "12070001".to_string(),
+///     ..AppIdentity::android()
+/// };
+/// assert_eq!(identity.app_version(), "Homegate/12.7.0/12070001/Android/30");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct AppIdentity {
+    /// Platform name, e.g. `Android` or `iOS`
+    pub platform: String,
+    /// App version, e.g. `12.6.0`
+    pub version: String,
+    /// App build number, e.g. `12060003`
+    pub build: String,
+    /// Operating system SDK level (Android API level or major iOS version)
+    pub sdk_level: u32,
+    /// Value of the `User-Agent` header, also part of the signed app ID
+    pub user_agent: String,
+    /// HMAC secret used to sign the app ID
+    pub secret: Vec<u8>,
+    /// HTTP Basic username
+    pub username: String,
+    /// HTTP Basic password
+    pub password: String,
+}
+
+impl AppIdentity {
+    /// Returns the identity of the Android app.
+    pub fn android() -> Self {
+        Self {
+            platform: "Android".to_string(),
+            version: "12.6.0".to_string(),
+            build: "12060003".to_string(),
+            sdk_level: 30,
+            user_agent: USER_AGENT.to_string(),
+            secret: SECRET.to_vec(),
+            username: API_USERNAME.to_string(),
+            password: API_PASSWORD.to_string(),
+        }
+    }
+
+    /// Returns the identity configured through the environment.
+    ///
+    /// Starts from the preset named by [`APP_PRESET_ENV`] (Android by default) and
+    /// applies the overrides from the other `HOMEGATE_APP_*` and `HOMEGATE_API_*`
+    /// variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the preset is unknown or the SDK level is not
+    /// a number.
+    pub fn from_env() -> crate::Result<Self> {
+        let mut identity = match env(APP_PRESET_ENV) {
+            Some(preset) => preset
+                .parse()
+                .map_err(crate::HomegateError::ValidationError)?,
+            None => Self::android(),
+        };
+
+        if let Some(platform) = env(APP_PLATFORM_ENV) {
+            identity.platform = platform;
+        }
+        if let Some(version) = env(APP_VERSION_ENV) {
+            identity.version = version;
+        }
+        if let Some(build) = env(APP_BUILD_ENV) {
+            identity.build = build;
+        }
+        if let Some(sdk_level) = env(APP_SDK_LEVEL_ENV) {
+            identity.sdk_level = sdk_level.parse().map_err(|e| {
+                crate::HomegateError::ValidationError(format!("{}: {}", APP_SDK_LEVEL_ENV, e))
+            })?;
+        }
+        if let Some(user_agent) = env(APP_USER_AGENT_ENV) {
+            identity.user_agent = user_agent;
+        }
+        if let Some(secret) = env(APP_SECRET_ENV) {
+            identity.secret = secret.into_bytes();
+        }
+        if let Some(username) = env(API_USERNAME_ENV) {
+            identity.username = username;
+        }
+        if let Some(password) = env(API_PASSWORD_ENV) {
+            identity.password = password;
+        }
+        Ok(identity)
+    }
+
+    /// Returns the value of the `X-App-Version` header.
+    pub fn app_version(&self) -> String {
+        format!(
+            "Homegate/{}/{}/{}/{}",
+            self.version, self.build, self.platform, self.sdk_level
+        )
+    }
+
+    /// Returns the value of the `Authorization` header.
+    pub fn authorization(&self) -> String {
+        let key = general_purpose::STANDARD.encode(format!("{}:{}", self.username, self.password));
+        format!("Basic {}", key)
+    }
+
+    /// Calculates the `X-App-Id` for the given time.
+    ///
+    /// The ID only changes once per minute.
+    pub fn app_id(&self, time: &chrono::NaiveDateTime) -> String {
+        let time_millis = time.and_utc().timestamp_millis() as u64;
+        let ceil = (f64::from((time_millis / 1000) as u32) / 60.0).ceil();
+        let s = format!("{}{}{}", self.user_agent, self.app_version(), ceil);
+
+        calculate_hmac(&self.secret, &s)
+    }
+}
+
+impl Default for AppIdentity {
+    fn default() -> Self {
+        Self::android()
+    }
+}
+
+impl std::fmt::Debug for AppIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppIdentity")
+            .field("platform", &self.platform)
+            .field("version", &self.version)
+            .field("build", &self.build)
+            .field("sdk_level", &self.sdk_level)
+            .field("user_agent", &self.user_agent)
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::str::FromStr for AppIdentity {
+    type Err = String;
+
+    /// Parses a preset name case-insensitively. Only `android` is supported.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "android" => Ok(Self::android()),
+            other => Err(format!(
+                "Unknown app identity preset: {}. Supported: android",
+                other
+            )),
+        }
+    }
+}
+
+/// Reads a non-empty environment variable.
+fn env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+#[tracing::instrument(level = "debug", skip(secret))]
+fn calculate_hmac(secret: &[u8], s: &str) -> String {
     tracing::debug!("Calculating HMAC for authentication");
-    let mut h: HmacSha256 = HmacSha256::new_from_slice(&SECRET).expect("SECRET is valid");
+    let mut h: HmacSha256 =
+        HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
     h.update(s.as_bytes());
     let result = h.finalize().into_bytes();
 
@@ -34,19 +222,20 @@ fn calculate_hmac(s: &str) -> String {
     }
 }
 
+/// Calculates the `X-App-Id` of the Android app for the given time.
+///
+/// See [`AppIdentity::app_id`] for other identities.
 #[tracing::instrument(level = "debug")]
 pub fn calculate_app_id(time: &chrono::NaiveDateTime) -> String {
     tracing::debug!("Generating App ID for authentication");
-    let time_millis = time.and_utc().timestamp_millis() as u64;
-    let ceil = (f64::from((time_millis / 1000) as u32) / 60.0).ceil();
-    let s = format!("{}{}{}", USER_AGENT, app_version(), ceil);
-
-    calculate_hmac(&s)
+    AppIdentity::android().app_id(time)
 }
 
+/// Returns the `X-App-Version` of the Android app.
+///
+/// See [`AppIdentity::app_version`] for other identities.
 pub fn app_version() -> String {
-    let sdk_version = 30;
-    format!("Homegate/12.6.0/12060003/Android/{}", sdk_version)
+    AppIdentity::android().app_version()
 }
 
 #[cfg(test)]
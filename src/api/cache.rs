@@ -0,0 +1,391 @@
+//! On-disk response cache for the Homegate HTTP client.
+//!
+//! Repeated CLI invocations and MCP tool calls often send identical requests. When a
+//! client is configured with a [`CacheConfig`], successful responses are stored on
+//! disk by the [`ResponseCache`] middleware and served from there until they expire.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use base64::Engine;
+use http::Extensions;
+use reqwest::header::HeaderMap;
+use reqwest::{Request, Response, ResponseBuilderExt, Url};
+use reqwest_middleware::{Middleware, Next};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::api::middleware::APP_ID_HEADER;
+use crate::clock::{Clock, SystemClock};
+use crate::config::CacheConfig;
+
+/// Extension of the files holding cache entries.
+const ENTRY_EXTENSION: &str = "json";
+
+/// Request headers left out of the cache key because they change over time.
+const VOLATILE_HEADERS: &[&str] = &[APP_ID_HEADER];
+
+/// Suffix making temporary file names unique among concurrent writes in a process.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Middleware answering repeated requests from an on-disk cache.
+///
+/// Only successful responses are stored. Cache failures are logged and never fail
+/// the request. Installed before the retry middleware, so cache hits neither wait
+/// for the rate limiter nor count against the daily budget. Entries are read and
+/// written on the blocking thread pool.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::cache::ResponseCache;
+/// use homegate::config::CacheConfig;
+/// use reqwest_middleware::ClientBuilder;
+///
+/// let client = ClientBuilder::new(reqwest::Client::new())
+///     .with(ResponseCache::new(CacheConfig::default()))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    config: Arc<CacheConfig>,
+    clock: Arc<dyn Clock>,
+    /// Running total of the entry sizes, `None` until the directory is first scanned.
+    ///
+    /// Other processes may write to the same directory, so this is an estimate;
+    /// it is corrected whenever it exceeds the cap and the directory is rescanned.
+    size: Arc<Mutex<Option<u64>>>,
+}
+
+/// A response as stored on disk.
+///
+/// UTF-8 bodies are stored as text, so entries stay readable; any other body is
+/// stored base64-encoded in `body_base64`.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Unix timestamp (seconds) at which the response was stored
+    stored_at: i64,
+    status: u16,
+    content_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+impl ResponseCache {
+    /// Creates a cache using the given configuration and the system clock.
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            clock: Arc::new(SystemClock),
+            size: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Reads the time used to timestamp and expire entries from the given clock.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the configuration of this cache.
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Computes the cache key of a request.
+    ///
+    /// The key is the SHA-256 of the method, the URL, the request headers and the
+    /// request body. Including the headers keeps clients with a different app
+    /// identity or extra headers from sharing entries; the time-based `X-App-Id`
+    /// header is left out. JSON bodies are canonicalized first, so their key order
+    /// does not matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method, e.g. `POST`
+    /// * `url` - Full request URL including the query string
+    /// * `headers` - Request headers
+    /// * `body` - Request body, if any
+    ///
+    /// # Returns
+    ///
+    /// The key as a lowercase hex string.
+    pub fn cache_key(method: &str, url: &str, headers: &HeaderMap, body: Option<&[u8]>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(method.as_bytes());
+        hasher.update(b" ");
+        hasher.update(url.as_bytes());
+
+        let mut header_lines: Vec<(&str, &[u8])> = headers
+            .iter()
+            .filter(|(name, _)| {
+                !VOLATILE_HEADERS
+                    .iter()
+                    .any(|volatile| name.as_str().eq_ignore_ascii_case(volatile))
+            })
+            .map(|(name, value)| (name.as_str(), value.as_bytes()))
+            .collect();
+        header_lines.sort();
+        for (name, value) in header_lines {
+            hasher.update(b"\n");
+            hasher.update(name.as_bytes());
+            hasher.update(b": ");
+            hasher.update(value);
+        }
+
+        if let Some(body) = body {
+            hasher.update(b"\n");
+            match serde_json::from_slice::<Value>(body) {
+                Ok(json) => hasher.update(canonical_json(&json).as_bytes()),
+                Err(_) => hasher.update(body),
+            }
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Removes every entry from the cache directory.
+    pub fn clear(&self) -> io::Result<()> {
+        let mut size = self.size.lock().unwrap_or_else(PoisonError::into_inner);
+        for path in self.entry_paths()? {
+            fs::remove_file(path)?;
+        }
+        *size = Some(0);
+        Ok(())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.config.dir.join(format!("{}.{}", key, ENTRY_EXTENSION))
+    }
+
+    fn entry_paths(&self) -> io::Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.config.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == ENTRY_EXTENSION) {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Loads a fresh entry, removing it if it has expired.
+    fn load(&self, key: &str) -> Option<CacheEntry> {
+        let path = self.entry_path(key);
+        let contents = fs::read_to_string(&path).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&contents) {
+            Ok(entry) => entry,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Ignoring unreadable cache entry");
+                return None;
+            }
+        };
+
+        let age = self.clock.now().timestamp().saturating_sub(entry.stored_at);
+        if Duration::from_secs(age.max(0) as u64) > self.config.ttl {
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+        Some(entry)
+    }
+
+    fn store(&self, key: &str, entry: &CacheEntry) -> io::Result<()> {
+        fs::create_dir_all(&self.config.dir)?;
+        let contents = serde_json::to_string(entry).map_err(io::Error::other)?;
+
+        // Write to a temporary file first so readers never see a partial entry
+        let path = self.entry_path(key);
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut size = self.size.lock().unwrap_or_else(PoisonError::into_inner);
+        let replaced = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        fs::write(&tmp, &contents)?;
+        if let Err(e) = fs::rename(&tmp, &path) {
+            let _ = fs::remove_file(&tmp);
+            return Err(e);
+        }
+
+        // Only rescan the directory when the total is unknown or over the cap
+        let total = match *size {
+            Some(total) => total.saturating_sub(replaced) + contents.len() as u64,
+            None => u64::MAX,
+        };
+        if total > self.config.max_size_bytes {
+            *size = Some(self.evict()?);
+        } else {
+            *size = Some(total);
+        }
+        Ok(())
+    }
+
+    /// Removes the oldest entries until the cache fits in `max_size_bytes`.
+    ///
+    /// Returns the size of the remaining entries.
+    fn evict(&self) -> io::Result<u64> {
+        let mut entries = Vec::new();
+        let mut total = 0;
+        for path in self.entry_paths()? {
+            let metadata = fs::metadata(&path)?;
+            total += metadata.len();
+            entries.push((metadata.modified()?, metadata.len(), path));
+        }
+        if total <= self.config.max_size_bytes {
+            return Ok(total);
+        }
+
+        entries.sort();
+        for (_, len, path) in entries {
+            if total <= self.config.max_size_bytes {
+                break;
+            }
+            fs::remove_file(&path)?;
+            total -= len;
+        }
+        Ok(total)
+    }
+}
+
+impl CacheEntry {
+    fn into_response(self, url: Url) -> reqwest_middleware::Result<Response> {
+        let body = self.body_bytes()?;
+        let mut builder = http::Response::builder().status(self.status).url(url);
+        if let Some(content_type) = self.content_type {
+            builder = builder.header(reqwest::header::CONTENT_TYPE, content_type);
+        }
+        let response = builder
+            .body(body)
+            .map_err(reqwest_middleware::Error::middleware)?;
+        Ok(Response::from(response))
+    }
+
+    fn body_bytes(&self) -> reqwest_middleware::Result<Vec<u8>> {
+        match (&self.body, &self.body_base64) {
+            (_, Some(encoded)) => base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(reqwest_middleware::Error::middleware),
+            (Some(body), None) => Ok(body.clone().into_bytes()),
+            (None, None) => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Serializes JSON with object keys sorted at every level.
+fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let fields: Vec<String> = keys
+                .into_iter()
+                .map(|key| {
+                    format!(
+                        "{}:{}",
+                        Value::from(key.as_str()),
+                        canonical_json(&map[key])
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(canonical_json).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for ResponseCache {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        // Streaming bodies cannot be inspected, so such requests bypass the cache
+        let body = match req.body() {
+            Some(body) => match body.as_bytes() {
+                Some(bytes) => Some(bytes),
+                None => return next.run(req, extensions).await,
+            },
+            None => None,
+        };
+        let key = Self::cache_key(
+            req.method().as_str(),
+            req.url().as_str(),
+            req.headers(),
+            body,
+        );
+        let url = req.url().clone();
+
+        if !self.config.refresh {
+            let cache = self.clone();
+            let lookup = key.clone();
+            let entry = tokio::task::spawn_blocking(move || cache.load(&lookup))
+                .await
+                .map_err(reqwest_middleware::Error::middleware)?;
+            if let Some(entry) = entry {
+                tracing::debug!(%url, "Serving response from cache");
+                return entry.into_response(url);
+            }
+        }
+
+        let response = next.run(req, extensions).await?;
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let bytes = response.bytes().await?.to_vec();
+        let (body, body_base64) = match String::from_utf8(bytes) {
+            Ok(text) => (Some(text), None),
+            Err(e) => (
+                None,
+                Some(base64::engine::general_purpose::STANDARD.encode(e.into_bytes())),
+            ),
+        };
+
+        let entry = CacheEntry {
+            stored_at: self.clock.now().timestamp(),
+            status: status.as_u16(),
+            content_type,
+            body,
+            body_base64,
+        };
+        let cache = self.clone();
+        let (entry, stored) = tokio::task::spawn_blocking(move || {
+            let stored = cache.store(&key, &entry);
+            (entry, stored)
+        })
+        .await
+        .map_err(reqwest_middleware::Error::middleware)?;
+        if let Err(e) = stored {
+            tracing::warn!(error = %e, "Failed to store response in cache");
+        }
+        entry.into_response(url)
+    }
+}
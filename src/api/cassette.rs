@@ -0,0 +1,282 @@
+//! Record/replay transport for deterministic tests.
+//!
+//! A [`Cassette`] either records every interaction with the API to a JSON file or
+//! replays the recorded responses without network access. It is installed as the
+//! outermost `reqwest-middleware` layer when the client is configured with a
+//! [`CassetteConfig`] or through the [`CASSETTE_ENV`](crate::config::CASSETTE_ENV)
+//! environment variable.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use http::Extensions;
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::{CassetteConfig, CassetteMode};
+use crate::HomegateError;
+
+/// Contents of a cassette file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CassetteFile {
+    /// Recorded interactions, in the order they happened
+    pub interactions: Vec<Interaction>,
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent to the API
+    pub request: RecordedRequest,
+    /// The response returned by the API
+    pub response: RecordedResponse,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+    /// Path of the URL including the query string, e.g. `/geo/locations?name=Bern`
+    pub path: String,
+    /// Request body, parsed as JSON when possible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Response body
+    pub body: String,
+}
+
+/// Middleware recording interactions to, or replaying them from, a cassette file.
+///
+/// Clones share the same cassette. When replaying, each recorded interaction is
+/// served once in order; once all matching interactions have been used, the last
+/// one keeps being served.
+///
+/// # Examples
+///
+/// ```no_run
+/// use homegate::api::request::HomegateClient;
+/// use homegate::config::{CassetteConfig, CassetteMode};
+///
+/// # fn example() -> homegate::Result<()> {
+/// let client = HomegateClient::builder()
+///     .cassette(CassetteConfig {
+///         path: "tests/cassettes/search.json".into(),
+///         mode: CassetteMode::Replay,
+///     })
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: CassetteConfig,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    file: CassetteFile,
+    /// Whether each interaction has been replayed already
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// Opens a cassette.
+    ///
+    /// In replay mode the cassette file is read immediately. In record mode the
+    /// file is created or truncated when the first interaction is recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HomegateError::Cassette`] if a cassette to replay cannot be read
+    /// or parsed.
+    pub fn new(config: CassetteConfig) -> crate::Result<Self> {
+        let file = match config.mode {
+            CassetteMode::Record => CassetteFile::default(),
+            CassetteMode::Replay => read_cassette(&config.path)?,
+        };
+        let used = vec![false; file.interactions.len()];
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                config,
+                state: Mutex::new(State { file, used }),
+            }),
+        })
+    }
+
+    /// Returns the configuration of this cassette.
+    pub fn config(&self) -> &CassetteConfig {
+        &self.inner.config
+    }
+
+    /// Returns a copy of the interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().file.interactions.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Finds the recorded response for a request.
+    fn replay(&self, request: &RecordedRequest) -> crate::Result<RecordedResponse> {
+        let mut state = self.lock();
+        let matching: Vec<usize> = state
+            .file
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| &interaction.request == request)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let idx = matching
+            .iter()
+            .copied()
+            .find(|&idx| !state.used[idx])
+            .or_else(|| matching.last().copied())
+            .ok_or_else(|| {
+                HomegateError::Cassette(format!(
+                    "no recorded interaction for {} {} in {}",
+                    request.method,
+                    request.path,
+                    self.inner.config.path.display()
+                ))
+            })?;
+        state.used[idx] = true;
+        Ok(state.file.interactions[idx].response.clone())
+    }
+
+    /// Appends an interaction and rewrites the cassette file.
+    fn record(&self, interaction: Interaction) -> crate::Result<()> {
+        let mut state = self.lock();
+        state.file.interactions.push(interaction);
+        state.used.push(true);
+        write_cassette(&self.inner.config.path, &state.file)
+    }
+}
+
+fn read_cassette(path: &Path) -> crate::Result<CassetteFile> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        HomegateError::Cassette(format!("failed to read {}: {}", path.display(), e))
+    })?;
+    serde_json::from_str(&contents)
+        .map_err(|e| HomegateError::Cassette(format!("failed to parse {}: {}", path.display(), e)))
+}
+
+fn write_cassette(path: &Path, file: &CassetteFile) -> crate::Result<()> {
+    let contents = serde_json::to_string_pretty(file)?;
+    let write = || -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    };
+    write()
+        .map_err(|e| HomegateError::Cassette(format!("failed to write {}: {}", path.display(), e)))
+}
+
+/// Captures the parts of a request used for matching.
+///
+/// JSON bodies are parsed, so formatting and key order do not affect matching.
+fn recorded_request(req: &Request) -> RecordedRequest {
+    let url = req.url();
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    let body =
+        req.body().and_then(|body| body.as_bytes()).map(|bytes| {
+            match serde_json::from_slice(bytes) {
+                Ok(json) => json,
+                Err(_) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+            }
+        });
+
+    RecordedRequest {
+        method: req.method().to_string(),
+        path,
+        body,
+    }
+}
+
+impl RecordedResponse {
+    fn into_response(self, url: reqwest::Url) -> reqwest_middleware::Result<Response> {
+        let mut builder = http::Response::builder().status(self.status).url(url);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body)
+            .map_err(reqwest_middleware::Error::middleware)?;
+        Ok(Response::from(response))
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Cassette {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let request = recorded_request(&req);
+        let url = req.url().clone();
+
+        if self.inner.config.mode == CassetteMode::Replay {
+            let response = self
+                .replay(&request)
+                .map_err(reqwest_middleware::Error::middleware)?;
+            return response.into_response(url);
+        }
+
+        let response = next.run(req, extensions).await?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            // The body is stored decoded, so its framing headers no longer apply
+            .filter(|(name, _)| {
+                *name != reqwest::header::CONTENT_LENGTH
+                    && *name != reqwest::header::TRANSFER_ENCODING
+                    && *name != reqwest::header::CONTENT_ENCODING
+            })
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = response.text().await?;
+
+        let recorded = RecordedResponse {
+            status,
+            headers,
+            body,
+        };
+        self.record(Interaction {
+            request,
+            response: recorded.clone(),
+        })
+        .map_err(reqwest_middleware::Error::middleware)?;
+        recorded.into_response(url)
+    }
+}
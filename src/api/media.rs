@@ -0,0 +1,310 @@
+//! Download and archive the media of a listing.
+//!
+//! Listings and their photos disappear from Homegate once a property is rented or
+//! sold. A [`MediaArchiver`] downloads every attachment of a listing into a
+//! directory, next to a copy of the listing itself and a [`MediaManifest`]
+//! describing what was archived.
+//!
+//! Media is served by Homegate's CDN rather than the API, so it is downloaded with
+//! a plain HTTP client: no authentication headers are sent and the API's rate
+//! limit, cache and cassette are not involved.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Proxy};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use crate::clock::{Clock, SystemClock};
+use crate::config::HomegateConfig;
+use crate::models::listing::AttachmentKind;
+use crate::models::listing_detail::ListingDetail;
+use crate::HomegateError;
+
+/// Name of the manifest file written to the archive directory.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Name of the file the listing details are written to in the archive directory.
+pub const LISTING_FILE: &str = "listing.json";
+
+/// Description of an archived listing, stored as [`MANIFEST_FILE`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaManifest {
+    /// Identifier of the archived listing
+    pub listing_id: String,
+    /// Title of the listing in its primary language, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Time the archive was created
+    pub archived_at: DateTime<Utc>,
+    /// Archived attachments, in listing order
+    pub items: Vec<MediaItem>,
+}
+
+impl MediaManifest {
+    /// Returns the items that could not be downloaded.
+    pub fn failed(&self) -> impl Iterator<Item = &MediaItem> {
+        self.items.iter().filter(|item| item.error.is_some())
+    }
+}
+
+/// An attachment recorded in a [`MediaManifest`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaItem {
+    /// Kind of attachment
+    pub kind: AttachmentKind,
+    /// URL the attachment was downloaded from
+    pub url: String,
+    /// Caption of the attachment, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Name of the downloaded file, relative to the archive directory
+    ///
+    /// `None` if the download failed, or for virtual tours, which are links
+    /// rather than files and are only recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Size of the downloaded file in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// Hex-encoded SHA-256 digest of the downloaded file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Reason the download failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Downloads the attachments of listings into archive directories.
+///
+/// # Examples
+///
+/// ```no_run
+/// use homegate::api::media::MediaArchiver;
+/// use homegate::api::request::HomegateClient;
+///
+/// # async fn example() -> homegate::Result<()> {
+/// let client = HomegateClient::new()?;
+/// let detail = client.get_listing("3001439887").await?;
+///
+/// let manifest = MediaArchiver::new().archive(&detail, "archive/3001439887").await?;
+/// println!("Archived {} attachments", manifest.items.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MediaArchiver {
+    http: Client,
+    timeout: Option<Duration>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MediaArchiver {
+    /// Creates an archiver with a default HTTP client.
+    pub fn new() -> Self {
+        Self {
+            http: Client::new(),
+            timeout: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Creates an archiver using the timeout and proxy of a client configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is invalid or the HTTP client cannot be
+    /// built.
+    pub fn from_config(config: &HomegateConfig) -> crate::Result<Self> {
+        let mut client_builder = Client::builder();
+        if let Some(proxy) = &config.proxy {
+            client_builder = client_builder.proxy(Proxy::all(proxy)?);
+        }
+        Ok(Self {
+            http: client_builder.build()?,
+            timeout: Some(config.timeout),
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Uses the given HTTP client for downloads.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// Sets the timeout of each download.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Uses the given clock for the manifest's `archived_at` time.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Archives a listing and its attachments into a directory.
+    ///
+    /// The directory is created if needed. It receives the listing details as
+    /// [`LISTING_FILE`], one file per attachment prefixed with its position (e.g.
+    /// `01-image_1.jpeg`) and the [`MediaManifest`] as [`MANIFEST_FILE`]. Archiving
+    /// the same listing again overwrites the previous archive.
+    ///
+    /// Attachments that fail to download are recorded in the manifest with their
+    /// error instead of aborting the archive; see [`MediaManifest::failed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `detail` - Listing to archive, as returned by
+    ///   [`HomegateClient::get_listing`](crate::api::request::HomegateClient::get_listing)
+    /// * `dir` - Directory to write the archive to
+    ///
+    /// # Returns
+    ///
+    /// The manifest written to the directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HomegateError::Media`] if the directory or one of its files cannot
+    /// be written.
+    #[tracing::instrument(level = "info", skip(self, detail, dir), fields(listing = %detail.id))]
+    pub async fn archive(
+        &self,
+        detail: &ListingDetail,
+        dir: impl AsRef<Path>,
+    ) -> crate::Result<MediaManifest> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)
+            .await
+            .map_err(|e| media_error(dir, e))?;
+        write_json(dir, LISTING_FILE, detail).await?;
+
+        let mut items = Vec::new();
+        for (idx, attachment) in detail.attachments().into_iter().enumerate() {
+            let mut item = MediaItem {
+                kind: attachment.t.clone(),
+                url: attachment.url.clone(),
+                title: attachment.title.clone(),
+                file: None,
+                size: None,
+                sha256: None,
+                error: None,
+            };
+
+            if attachment.t != AttachmentKind::VirtualTour {
+                match self.download(&attachment.url).await {
+                    Ok(bytes) => {
+                        let file = file_name(idx, &attachment.file, &attachment.url);
+                        let path = dir.join(&file);
+                        fs::write(&path, &bytes)
+                            .await
+                            .map_err(|e| media_error(&path, e))?;
+                        item.size = Some(bytes.len() as u64);
+                        item.sha256 = Some(hex(&Sha256::digest(&bytes)));
+                        item.file = Some(file);
+                    }
+                    Err(e) => {
+                        tracing::warn!(url = %attachment.url, error = %e, "Failed to download attachment");
+                        item.error = Some(e.to_string());
+                    }
+                }
+            }
+            items.push(item);
+        }
+
+        let manifest = MediaManifest {
+            listing_id: detail.id.clone(),
+            title: detail
+                .localization
+                .resolve(&[])
+                .map(|entry| entry.text.title.clone()),
+            archived_at: self.clock.now(),
+            items,
+        };
+        write_json(dir, MANIFEST_FILE, &manifest).await?;
+
+        tracing::info!(
+            "Archived {} attachments ({} failed)",
+            manifest.items.len(),
+            manifest.failed().count()
+        );
+        Ok(manifest)
+    }
+
+    async fn download(&self, url: &str) -> crate::Result<Vec<u8>> {
+        let mut request = self.http.get(url);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(HomegateError::from_status(status, None, &body));
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+impl Default for MediaArchiver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds the archive file name of an attachment.
+///
+/// The name is prefixed with the attachment's position, so attachments sharing a
+/// file name do not overwrite each other, and limited to characters that are safe
+/// on every file system.
+fn file_name(idx: usize, file: &str, url: &str) -> String {
+    let original = if file.trim().is_empty() {
+        url.split(['?', '#'])
+            .next()
+            .and_then(|path| path.rsplit('/').next())
+            .unwrap_or_default()
+    } else {
+        file
+    };
+    let sanitized: String = original
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let sanitized = sanitized.trim_start_matches('.');
+    let sanitized = if sanitized.is_empty() {
+        "attachment"
+    } else {
+        sanitized
+    };
+    format!("{:02}-{}", idx + 1, sanitized)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+async fn write_json(dir: &Path, name: &str, value: &impl Serialize) -> crate::Result<()> {
+    let path = dir.join(name);
+    let contents = serde_json::to_string_pretty(value)?;
+    fs::write(&path, contents)
+        .await
+        .map_err(|e| media_error(&path, e))
+}
+
+fn media_error(path: &Path, err: std::io::Error) -> HomegateError {
+    HomegateError::Media(format!("failed to write {}: {}", path.display(), err))
+}
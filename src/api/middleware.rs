@@ -0,0 +1,112 @@
+//! Middleware layers for the Homegate HTTP client.
+//!
+//! This module contains `reqwest-middleware` layers that are installed on every
+//! [`HomegateClient`](crate::api::request::HomegateClient).
+
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use http::Extensions;
+use reqwest::header::HeaderValue;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+
+use crate::api::app_id::AppIdentity;
+use crate::clock::{Clock, SystemClock};
+
+/// Name of the header carrying the time-based app identifier.
+pub const APP_ID_HEADER: &str = "X-App-Id";
+
+/// Middleware computing a fresh `X-App-Id` header for every request.
+///
+/// The app ID returned by [`AppIdentity::app_id`] changes every minute, so it cannot be
+/// baked into the client's default headers. This middleware recomputes it each time
+/// a request passes through, including every retry attempt when installed after the
+/// retry middleware.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::middleware::AppIdMiddleware;
+/// use reqwest_middleware::ClientBuilder;
+///
+/// let fixed = chrono::NaiveDate::from_ymd_opt(2022, 1, 25)
+///     .unwrap()
+///     .and_hms_opt(1, 30, 56)
+///     .unwrap();
+/// let client = ClientBuilder::new(reqwest::Client::new())
+///     .with(AppIdMiddleware::with_clock(move || fixed))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AppIdMiddleware {
+    clock: Arc<dyn Clock>,
+    identity: Arc<AppIdentity>,
+}
+
+impl AppIdMiddleware {
+    /// Creates a middleware using the system clock and the Android app identity.
+    pub fn new() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            identity: Arc::new(AppIdentity::default()),
+        }
+    }
+
+    /// Creates a middleware using the given function, returning the current UTC time.
+    pub fn with_clock(now: impl Fn() -> NaiveDateTime + Send + Sync + 'static) -> Self {
+        Self::new().clock(Arc::new(FnClock(now)))
+    }
+
+    /// Reads the time from the given clock.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Signs app IDs with the given identity instead of the Android app's.
+    pub fn identity(mut self, identity: AppIdentity) -> Self {
+        self.identity = Arc::new(identity);
+        self
+    }
+}
+
+impl Default for AppIdMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adapts a function returning the current UTC time to a [`Clock`].
+struct FnClock<F>(F);
+
+impl<F> Clock for FnClock<F>
+where
+    F: Fn() -> NaiveDateTime + Send + Sync,
+{
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        (self.0)().and_utc()
+    }
+}
+
+impl<F> std::fmt::Debug for FnClock<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnClock").finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for AppIdMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let app_id = self.identity.app_id(&self.clock.now().naive_utc());
+        let value =
+            HeaderValue::from_str(&app_id).map_err(reqwest_middleware::Error::middleware)?;
+        req.headers_mut().insert(APP_ID_HEADER, value);
+        next.run(req, extensions).await
+    }
+}
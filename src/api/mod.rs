@@ -4,8 +4,14 @@
 //! Homegate API, including request handling and search capabilities.
 
 pub mod app_id;
+pub mod cache;
+pub mod cassette;
+pub mod media;
+pub mod middleware;
+pub mod rate_limit;
 pub mod request;
 pub mod search;
+pub mod transport;
 
 /// The base URL for Homegate's API backend.
 ///
@@ -0,0 +1,361 @@
+//! Client-side rate limiting for the Homegate HTTP client.
+//!
+//! The unofficial API bans accounts that send too many requests, so clients can be
+//! configured with a [`RateLimitConfig`] to throttle themselves. The resulting
+//! [`RateLimiter`] is installed as a `reqwest-middleware` layer.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{Local, NaiveDate};
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, SystemClock};
+use crate::config::RateLimitConfig;
+
+/// Token bucket rate limiter with an optional daily budget.
+///
+/// Cloning a `RateLimiter` is cheap and the clones share the same bucket and
+/// budget. The bucket refills with monotonic time, while the daily budget rolls
+/// over at local midnight as reported by its [`Clock`]. When installed on a [`HomegateClient`](crate::api::request::HomegateClient)
+/// it is shared by all clones of that client.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::rate_limit::RateLimiter;
+/// use homegate::config::RateLimitConfig;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let limiter = RateLimiter::new(&RateLimitConfig {
+///     requests_per_second: 2.0,
+///     burst: 1,
+///     ..RateLimitConfig::default()
+/// })?;
+///
+/// limiter.acquire().await?; // immediate
+/// limiter.acquire().await?; // waits about 500ms
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    inner: Arc<Inner>,
+    clock: Arc<dyn Clock>,
+}
+
+struct Inner {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+    budget: Option<DailyBudget>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+struct DailyBudget {
+    limit: u32,
+    file: Option<PathBuf>,
+    state: Mutex<BudgetState>,
+}
+
+/// Persisted state of the daily budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BudgetState {
+    date: NaiveDate,
+    used: u32,
+}
+
+impl BudgetState {
+    fn new(date: NaiveDate) -> Self {
+        Self { date, used: 0 }
+    }
+
+    /// Resets the counter if the stored date is not `today`.
+    fn roll_over(&mut self, today: NaiveDate) {
+        if self.date != today {
+            *self = Self::new(today);
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter from the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if `requests_per_second` is not a positive number,
+    /// if `burst` is zero or if `daily_budget` is zero.
+    pub fn new(config: &RateLimitConfig) -> crate::Result<Self> {
+        if !(config.requests_per_second.is_finite() && config.requests_per_second > 0.0) {
+            return Err(crate::HomegateError::ValidationError(format!(
+                "requests_per_second must be a positive number, got {}",
+                config.requests_per_second
+            )));
+        }
+        if config.burst == 0 {
+            return Err(crate::HomegateError::ValidationError(
+                "burst must be at least 1".to_string(),
+            ));
+        }
+        if config.daily_budget == Some(0) {
+            return Err(crate::HomegateError::ValidationError(
+                "daily_budget must be at least 1".to_string(),
+            ));
+        }
+
+        let budget = config.daily_budget.map(|limit| DailyBudget {
+            limit,
+            file: config.budget_file.clone(),
+            state: Mutex::new(BudgetState::new(Local::now().date_naive())),
+        });
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                requests_per_second: config.requests_per_second,
+                burst: f64::from(config.burst),
+                bucket: Mutex::new(Bucket {
+                    tokens: f64::from(config.burst),
+                    last_refill: Instant::now(),
+                }),
+                budget,
+            }),
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Reads the date used to roll over the daily budget from the given clock.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns today's date in the local time zone, according to the clock.
+    fn today(&self) -> NaiveDate {
+        self.clock.now().with_timezone(&Local).date_naive()
+    }
+
+    /// Waits until a request may be sent.
+    ///
+    /// The daily budget is charged first, so a request rejected for an exhausted
+    /// budget does not consume a token. A `tracing` event is emitted whenever the
+    /// call has to wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimitError::BudgetExhausted`] if the daily budget is used up, or an I/O error
+    /// if the budget file cannot be read or written.
+    pub async fn acquire(&self) -> Result<(), RateLimitError> {
+        if let Some(budget) = &self.inner.budget {
+            let today = self.today();
+            if budget.file.is_some() {
+                // The budget file is locked and rewritten with blocking I/O
+                let inner = self.inner.clone();
+                tokio::task::spawn_blocking(move || match &inner.budget {
+                    Some(budget) => budget.charge(today),
+                    None => Ok(()),
+                })
+                .await
+                .map_err(|e| RateLimitError::Io(std::io::Error::other(e)))??;
+            } else {
+                budget.charge(today)?;
+            }
+        }
+
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tracing::info!(
+                wait_ms = wait.as_millis() as u64,
+                "Rate limit reached, waiting before sending request"
+            );
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of requests left in today's budget, if one is configured.
+    pub fn remaining_budget(&self) -> Result<Option<u32>, RateLimitError> {
+        match &self.inner.budget {
+            Some(budget) => Ok(Some(budget.remaining(self.today())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Takes a token from the bucket and returns how long to wait before using it.
+    ///
+    /// Tokens may go negative, which queues callers fairly: each one waits for
+    /// the tokens reserved by the callers before it.
+    fn reserve(&self) -> Duration {
+        let inner = &self.inner;
+        let mut bucket = inner.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * inner.requests_per_second).min(inner.burst);
+        bucket.last_refill = now;
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / inner.requests_per_second)
+        }
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("requests_per_second", &self.inner.requests_per_second)
+            .field("burst", &self.inner.burst)
+            .field("clock", &self.clock)
+            .field(
+                "daily_budget",
+                &self.inner.budget.as_ref().map(|budget| budget.limit),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl DailyBudget {
+    /// Counts one request against today's budget.
+    fn charge(&self, today: NaiveDate) -> Result<(), RateLimitError> {
+        self.update(today, |state| {
+            if state.used >= self.limit {
+                return Err(RateLimitError::BudgetExhausted { budget: self.limit });
+            }
+            state.used += 1;
+            Ok(())
+        })
+    }
+
+    fn remaining(&self, today: NaiveDate) -> Result<u32, RateLimitError> {
+        self.update(today, |state| Ok(self.limit.saturating_sub(state.used)))
+    }
+
+    /// Runs `f` on today's state, persisting it when a budget file is configured.
+    fn update<T>(
+        &self,
+        today: NaiveDate,
+        f: impl FnOnce(&mut BudgetState) -> Result<T, RateLimitError>,
+    ) -> Result<T, RateLimitError> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match &self.file {
+            Some(path) => update_file(path, today, f),
+            None => {
+                state.roll_over(today);
+                f(&mut state)
+            }
+        }
+    }
+}
+
+/// Reads, updates and writes back the budget file while holding an exclusive lock.
+fn update_file<T>(
+    path: &Path,
+    today: NaiveDate,
+    f: impl FnOnce(&mut BudgetState) -> Result<T, RateLimitError>,
+) -> Result<T, RateLimitError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    file.lock()?;
+
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    let mut state = match serde_json::from_str::<BudgetState>(&contents) {
+        Ok(state) => state,
+        Err(e) => {
+            if !contents.trim().is_empty() {
+                tracing::warn!(path = %path.display(), error = %e, "Resetting unreadable budget file");
+            }
+            BudgetState::new(today)
+        }
+    };
+    state.roll_over(today);
+
+    let result = f(&mut state);
+
+    let contents = serde_json::to_string(&state).map_err(std::io::Error::other)?;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(contents.as_bytes())?;
+    file.unlock()?;
+
+    result
+}
+
+/// Error returned by [`RateLimiter::acquire`].
+///
+/// Converted into a [`HomegateError`](crate::HomegateError) by the client.
+#[derive(Debug)]
+pub enum RateLimitError {
+    /// The daily budget is used up
+    BudgetExhausted {
+        /// The configured number of requests per day
+        budget: u32,
+    },
+    /// The budget file could not be read or written
+    Io(std::io::Error),
+}
+
+impl fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitError::BudgetExhausted { budget } => {
+                write!(f, "daily request budget of {} exhausted", budget)
+            }
+            RateLimitError::Io(e) => write!(f, "failed to update budget file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RateLimitError {}
+
+impl From<std::io::Error> for RateLimitError {
+    fn from(err: std::io::Error) -> Self {
+        RateLimitError::Io(err)
+    }
+}
+
+impl From<RateLimitError> for crate::HomegateError {
+    fn from(err: RateLimitError) -> Self {
+        match err {
+            RateLimitError::BudgetExhausted { budget } => {
+                crate::HomegateError::BudgetExhausted { budget }
+            }
+            RateLimitError::Io(e) => crate::HomegateError::Middleware(e.to_string()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimiter {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.acquire()
+            .await
+            .map_err(reqwest_middleware::Error::middleware)?;
+        next.run(req, extensions).await
+    }
+}
@@ -3,27 +3,44 @@
 //! This module provides low-level HTTP client functionality with proper authentication
 //! headers and app identification for communicating with the Homegate backend.
 
-use base64::{engine::general_purpose, Engine as _};
-use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
 use reqwest::header;
-use reqwest::header::HeaderValue;
-use reqwest::{Client, ClientBuilder, Response, Url};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Method, Proxy, Request, Response, Url};
 use reqwest_middleware::{ClientBuilder as MiddlewareClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 
-use crate::api::app_id::{app_version, calculate_app_id};
-use crate::api::{API_PASSWORD, API_USERNAME, USER_AGENT};
+use crate::api::app_id::AppIdentity;
+use crate::api::cache::ResponseCache;
+use crate::api::cassette::Cassette;
+use crate::api::middleware::{AppIdMiddleware, APP_ID_HEADER};
+use crate::api::rate_limit::RateLimiter;
+use crate::api::search::{default_search, Location, SearchAllOptions, SearchQuery, SearchRequest};
+use crate::api::transport::Transport;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{CacheConfig, CassetteConfig, HomegateConfig, ParseMode, RateLimitConfig};
+use crate::models::listing_detail::ListingDetail;
+use crate::models::location::{Location as GeoLocation, LocationSuggestions};
+use crate::models::paginated::{parse_search_result, parse_search_result_lenient, Paginated};
+use crate::models::realestate::RealEstate;
+
+/// Maximum number of suggestions requested from the geo lookup endpoint.
+const GEO_LOOKUP_SIZE: u32 = 10;
 
 /// HTTP client for the Homegate API with persistent connection pooling.
 ///
-/// This struct maintains a configured reqwest `ClientWithMiddleware` instance that can be reused
-/// across multiple requests, avoiding the overhead of rebuilding the client and
-/// connection pool for each request.
+/// This struct maintains a configured [`Transport`], by default a reqwest
+/// `ClientWithMiddleware` instance, that can be reused across multiple requests,
+/// avoiding the overhead of rebuilding the client and connection pool for each request.
 ///
 /// # Performance Benefits
 ///
 /// - Reuses TCP connections via connection pooling
 /// - Avoids recreating authentication headers on each request
+/// - Computes a fresh `X-App-Id` for every request, so long-lived clients stay valid
 /// - Reduces allocation overhead from repeated client construction
 /// - Includes automatic retry logic for transient failures
 ///
@@ -40,8 +57,15 @@ use crate::api::{API_PASSWORD, API_USERNAME, USER_AGENT};
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone, Debug)]
 pub struct HomegateClient {
-    client: ClientWithMiddleware,
+    transport: Arc<dyn Transport>,
+    clock: Arc<dyn Clock>,
+    identity: Arc<AppIdentity>,
+    backend_url: String,
+    headers: HeaderMap,
+    timeout: Duration,
+    parse_mode: ParseMode,
 }
 
 impl HomegateClient {
@@ -66,7 +90,53 @@ impl HomegateClient {
     /// # }
     /// ```
     pub fn new() -> crate::Result<Self> {
-        Self::with_retries(3)
+        Self::from_config(&HomegateConfig::default())
+    }
+
+    /// Creates a new Homegate API client from a [`HomegateConfig`].
+    ///
+    /// Every field of the configuration is honored: backend URL, timeout, retries,
+    /// proxy and extra headers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use homegate::api::request::HomegateClient;
+    /// use homegate::config::HomegateConfig;
+    ///
+    /// # fn example() -> homegate::Result<()> {
+    /// let config = HomegateConfig {
+    ///     backend_url: "http://127.0.0.1:8080".to_string(),
+    ///     ..HomegateConfig::default()
+    /// };
+    /// let client = HomegateClient::from_config(&config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_config(config: &HomegateConfig) -> crate::Result<Self> {
+        HomegateClientBuilder::from_config(config.clone()).build()
+    }
+
+    /// Returns a [`HomegateClientBuilder`] initialized with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use homegate::api::request::HomegateClient;
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> homegate::Result<()> {
+    /// let client = HomegateClient::builder()
+    ///     .backend_url("https://staging.example.com")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .max_retries(1)
+    ///     .header("X-Debug", "1")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> HomegateClientBuilder {
+        HomegateClientBuilder::default()
     }
 
     /// Creates a new Homegate API client with custom retry settings.
@@ -80,8 +150,40 @@ impl HomegateClient {
     /// Returns a configured `HomegateClient` on success, or a `HomegateError` if
     /// client construction fails.
     pub fn with_retries(max_retries: u32) -> crate::Result<Self> {
-        let client = build_client(max_retries)?;
-        Ok(Self { client })
+        Self::builder().max_retries(max_retries).build()
+    }
+
+    /// Returns the base URL all endpoints are resolved against.
+    pub fn backend_url(&self) -> &str {
+        &self.backend_url
+    }
+
+    /// Returns the clock used for time-based logic such as the `X-App-Id` header.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Resolves an API path (e.g. `/search/listings`) against the backend URL.
+    pub fn endpoint(&self, path: &str) -> crate::Result<Url> {
+        Ok(Url::parse(&format!("{}{}", self.backend_url, path))?)
+    }
+
+    /// Creates a request carrying the authentication headers, the current
+    /// `X-App-Id` and the timeout.
+    ///
+    /// The `X-App-Id` is added here so that every [`Transport`] receives fully
+    /// authenticated requests. The default transport refreshes it on each retry.
+    fn request(&self, method: Method, url: Url) -> crate::Result<Request> {
+        let mut req = Request::new(method, url);
+        *req.headers_mut() = self.headers.clone();
+        let app_id = self.identity.app_id(&self.clock.now().naive_utc());
+        req.headers_mut().insert(
+            APP_ID_HEADER,
+            HeaderValue::from_str(&app_id)
+                .map_err(|e| crate::HomegateError::InvalidHeader(e.to_string()))?,
+        );
+        *req.timeout_mut() = Some(self.timeout);
+        Ok(req)
     }
 
     /// Sends an authenticated POST request to the specified URL.
@@ -97,14 +199,126 @@ impl HomegateClient {
     #[tracing::instrument(level = "info", skip(self, body), fields(url = %url))]
     pub async fn post_url(&self, url: Url, body: &str) -> crate::Result<Response> {
         tracing::info!("Sending POST request via HomegateClient");
-        let req = self.client.post(url).body(body.to_string()).build()?;
-        Ok(self.client.execute(req).await?)
+        let mut req = self.request(Method::POST, url)?;
+        *req.body_mut() = Some(body.to_string().into());
+        self.transport.send(req).await
+    }
+
+    /// Sends an authenticated GET request to the specified URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to send the GET request to, including any query parameters
+    ///
+    /// # Returns
+    ///
+    /// Returns the HTTP `Response` on success, or a `HomegateError` if the request fails.
+    #[tracing::instrument(level = "info", skip(self), fields(url = %url))]
+    pub async fn get_url(&self, url: Url) -> crate::Result<Response> {
+        tracing::info!("Sending GET request via HomegateClient");
+        let req = self.request(Method::GET, url)?;
+        self.transport.send(req).await
+    }
+
+    /// Resolves free text into location suggestions.
+    ///
+    /// Accepts city names, postal codes or districts (e.g. "Winterthur", "8004",
+    /// "Kreis 4"). The returned locations can be passed to [`SearchQuery::new`] to
+    /// search by geo tag instead of coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Free text to resolve
+    ///
+    /// # Returns
+    ///
+    /// Returns the matching locations, best match first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use homegate::api::request::HomegateClient;
+    /// use homegate::api::search::SearchQuery;
+    ///
+    /// # async fn example() -> homegate::Result<()> {
+    /// let client = HomegateClient::new()?;
+    /// let suggestions = client.geo_lookup("Winterthur").await?;
+    /// if let Some(location) = suggestions.first() {
+    ///     let results = client.search_with(&SearchQuery::new(location)).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn geo_lookup(&self, text: &str) -> crate::Result<Vec<GeoLocation>> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(crate::HomegateError::ValidationError(
+                "geo lookup text must not be empty".to_string(),
+            ));
+        }
+
+        let mut url = self.endpoint("/geo/locations")?;
+        url.query_pairs_mut()
+            .append_pair("name", text)
+            .append_pair("size", &GEO_LOOKUP_SIZE.to_string());
+
+        let resp = error_for_status(self.get_url(url).await?, self.clock.as_ref()).await?;
+        let resp_text = resp.text().await?;
+        let suggestions: LocationSuggestions = serde_json::from_str(&resp_text)?;
+
+        tracing::info!("Geo lookup found {} locations", suggestions.results.len());
+        Ok(suggestions.results)
+    }
+
+    /// Fetches the full details of a single listing.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Listing identifier (as found in `Listing::id`)
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ListingDetail`] with descriptions, characteristics, features,
+    /// attachments and lister contact information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use homegate::api::request::HomegateClient;
+    ///
+    /// # async fn example() -> homegate::Result<()> {
+    /// let client = HomegateClient::new()?;
+    /// let detail = client.get_listing("3001439887").await?;
+    /// println!("Available from {:?}", detail.available_from);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get_listing(&self, id: &str) -> crate::Result<ListingDetail> {
+        let id = id.trim();
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(crate::HomegateError::ValidationError(format!(
+                "Invalid listing id: \"{}\"",
+                id
+            )));
+        }
+
+        let url = self.endpoint(&format!("/listings/{}", id))?;
+
+        let resp = error_for_status(self.get_url(url).await?, self.clock.as_ref()).await?;
+        let resp_text = resp.text().await?;
+        let detail: ListingDetail = serde_json::from_str(&resp_text)?;
+
+        tracing::info!("Fetched listing details");
+        Ok(detail)
     }
 
     /// Searches for real estate listings at the specified location.
     ///
     /// This is a convenience method that wraps the search API with proper error handling
-    /// and response parsing.
+    /// and response parsing. It uses the filters of [`default_search`] (minimum 60m²,
+    /// 500 CHF and 2 rooms); use [`HomegateClient::search_with`] for full control.
     ///
     /// # Arguments
     ///
@@ -133,25 +347,16 @@ impl HomegateClient {
     /// # }
     /// ```
     #[tracing::instrument(level = "info", skip(self), fields(lat = %location.latitude, lon = %location.longitude, radius = %location.radius))]
-    pub async fn search(
-        &self,
-        location: &crate::api::search::Location,
-    ) -> crate::Result<crate::models::paginated::Paginated<crate::models::realestate::RealEstate>>
-    {
-        use crate::api::search::default_search;
-        use crate::api::BACKEND_URL;
-        use crate::models::paginated::parse_search_result;
-
+    pub async fn search(&self, location: &Location) -> crate::Result<Paginated<RealEstate>> {
         // Validate location parameters
         location
             .validate()
             .map_err(crate::HomegateError::ValidationError)?;
 
         tracing::info!("Searching for real estate listings");
-        let url: Url = Url::parse(&format!("{}{}", BACKEND_URL, "/search/listings"))?;
 
         let mut search_request = default_search();
-        search_request.query.location = location.clone();
+        search_request.query.location = location.clone().into();
 
         // Validate FromTo ranges in the query
         search_request
@@ -172,80 +377,501 @@ impl HomegateClient {
                 crate::HomegateError::ValidationError(format!("number_of_rooms: {}", e))
             })?;
 
-        let search_request_json = serde_json::to_string(&search_request)?;
+        self.execute_search(&search_request).await
+    }
 
-        let resp = self.post_url(url, &search_request_json).await?;
+    /// Searches for real estate listings matching a [`SearchQuery`].
+    ///
+    /// Unlike [`HomegateClient::search`], only the filters set on the query are
+    /// applied. The query is validated before any request is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search criteria, pagination and sorting
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Paginated<RealEstate>` containing the requested page of results.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use homegate::api::request::HomegateClient;
+    /// use homegate::api::search::{Location, SearchQuery};
+    ///
+    /// # async fn example() -> homegate::Result<()> {
+    /// let client = HomegateClient::new()?;
+    /// let query = SearchQuery::new(Location {
+    ///     latitude: 47.36667,
+    ///     longitude: 8.55,
+    ///     radius: 1000,
+    /// })
+    /// .monthly_rent(None, Some(2500))
+    /// .page(2);
+    /// let results = client.search_with(&query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(level = "info", skip(self, query))]
+    pub async fn search_with(&self, query: &SearchQuery) -> crate::Result<Paginated<RealEstate>> {
+        let search_request = query.to_request()?;
+        tracing::info!("Searching for real estate listings");
+        self.execute_search(&search_request).await
+    }
+
+    /// Streams every listing matching a [`SearchQuery`] across all result pages.
+    ///
+    /// Pages are fetched lazily, starting at the query's page, until the API's
+    /// `maxFrom` or `total` is reached. Up to `options.concurrency` pages are
+    /// prefetched while the consumer processes results, and the stream ends after
+    /// `options.limit` listings if a limit is set.
+    ///
+    /// A failed page yields a single `Err` item; subsequent pages are still fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use homegate::api::request::HomegateClient;
+    /// use homegate::api::search::{Location, SearchAllOptions, SearchQuery};
+    ///
+    /// # async fn example() -> homegate::Result<()> {
+    /// let client = HomegateClient::new()?;
+    /// let query = SearchQuery::new(Location {
+    ///     latitude: 47.36667,
+    ///     longitude: 8.55,
+    ///     radius: 1000,
+    /// });
+    /// let options = SearchAllOptions {
+    ///     limit: Some(200),
+    ///     concurrency: 2,
+    /// };
+    ///
+    /// let mut listings = client.search_all(&query, options);
+    /// while let Some(real_estate) = listings.next().await {
+    ///     println!("{}", real_estate?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_all(
+        &self,
+        query: &SearchQuery,
+        options: SearchAllOptions,
+    ) -> BoxStream<'static, crate::Result<RealEstate>> {
+        let client = self.clone();
+        let query = query.clone();
+        let first_query = query.clone();
+        let first_client = client.clone();
+        let concurrency = options.concurrency.max(1);
+        let limit = options.limit;
+
+        let listings = stream::once(async move { first_client.search_with(&first_query).await })
+            .flat_map(move |first| match first {
+                Ok(page) => {
+                    let client = client.clone();
+                    let query = query.clone();
+                    let pages = query.following_pages(&page, limit);
+                    tracing::debug!("Streaming {} additional result pages", pages.len());
+
+                    let rest = stream::iter(pages)
+                        .map(move |n| {
+                            let client = client.clone();
+                            let query = query.clone().page(n);
+                            async move { client.search_with(&query).await }
+                        })
+                        .buffered(concurrency)
+                        .flat_map(page_results);
+                    page_results(Ok(page)).chain(rest).boxed()
+                }
+                Err(e) => page_results(Err(e)).boxed(),
+            });
+
+        match limit {
+            Some(limit) => listings.take(limit).boxed(),
+            None => listings.boxed(),
+        }
+    }
+
+    /// Sends a fully built search request and parses the response.
+    async fn execute_search(
+        &self,
+        search_request: &SearchRequest,
+    ) -> crate::Result<Paginated<RealEstate>> {
+        let url = self.endpoint("/search/listings")?;
+        let search_request_json = serde_json::to_string(search_request)?;
+
+        let resp = error_for_status(
+            self.post_url(url, &search_request_json).await?,
+            self.clock.as_ref(),
+        )
+        .await?;
         let resp_text = resp.text().await?;
-        let r = parse_search_result(&resp_text)?;
+        let r = match self.parse_mode {
+            ParseMode::Strict => parse_search_result(&resp_text)?,
+            ParseMode::Lenient => parse_search_result_lenient(&resp_text)?,
+        };
+        for skipped in &r.skipped {
+            tracing::warn!("Skipping malformed search result: {}", skipped);
+        }
 
         tracing::info!("Search completed successfully, found {} results", r.total);
         Ok(r)
     }
 }
 
+/// Converts a non-success response into the matching [`HomegateError`].
+///
+/// Successful responses are returned unchanged. For error responses, the body is
+/// read (and truncated) and the `Retry-After` header is parsed, either as a number
+/// of seconds or as an HTTP date relative to the client's clock.
+async fn error_for_status(resp: Response, clock: &dyn Clock) -> crate::Result<Response> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    let retry_after = resp
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|value| parse_retry_after(value, clock));
+    let body = resp.text().await.unwrap_or_default();
+
+    tracing::warn!("Request failed with status {}", status);
+    Err(crate::HomegateError::from_status(
+        status,
+        retry_after,
+        &body,
+    ))
+}
+
+/// Parses a `Retry-After` header value (delay in seconds or HTTP date).
+///
+/// HTTP dates are turned into a delay from the current time of `clock`; dates in
+/// the past mean no delay.
+fn parse_retry_after(value: &str, clock: &dyn Clock) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&chrono::Utc) - clock.now())
+        .to_std()
+        .ok()
+        .or(Some(Duration::ZERO))
+}
+
+/// Flattens a fetched result page into a stream of listings.
+fn page_results(
+    page: crate::Result<Paginated<RealEstate>>,
+) -> stream::Iter<std::vec::IntoIter<crate::Result<RealEstate>>> {
+    match page {
+        Ok(page) => stream::iter(page.results.into_iter().map(Ok).collect::<Vec<_>>()),
+        Err(e) => stream::iter(vec![Err(e)]),
+    }
+}
+
 impl Default for HomegateClient {
     fn default() -> Self {
         Self::new().expect("Failed to create default HomegateClient")
     }
 }
 
-/// Builds an authenticated HTTP client with required Homegate API headers.
+/// Builder for [`HomegateClient`].
 ///
-/// This function creates a reqwest Client configured with all necessary authentication
-/// headers including Basic Auth, app identification, and user agent.
+/// Obtained through [`HomegateClient::builder`]. Every setter mirrors a field of
+/// [`HomegateConfig`], with the addition of [`HomegateClientBuilder::http_client`]
+/// to inject a preconfigured `reqwest::Client`, [`HomegateClientBuilder::transport`]
+/// to replace the HTTP stack entirely and [`HomegateClientBuilder::clock`] to control
+/// time.
+#[derive(Debug, Default)]
+pub struct HomegateClientBuilder {
+    config: HomegateConfig,
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl HomegateClientBuilder {
+    /// Creates a builder initialized from an existing configuration.
+    pub fn from_config(config: HomegateConfig) -> Self {
+        Self {
+            config,
+            http_client: None,
+            transport: None,
+            clock: None,
+        }
+    }
+
+    /// Sets the base URL for the API (e.g. a staging mirror or a local stand-in).
+    pub fn backend_url(mut self, backend_url: impl Into<String>) -> Self {
+        self.config.backend_url = backend_url.into();
+        self
+    }
+
+    /// Sets the timeout applied to every request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum number of retry attempts for transient errors.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.config.max_retries = max_retries;
+        self
+    }
+
+    /// Routes all requests through the given proxy URL.
+    ///
+    /// Cannot be combined with [`HomegateClientBuilder::http_client`]; configure the
+    /// proxy on the injected client instead.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.config.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.extra_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Throttles requests according to the given rate limit configuration.
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.config.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Caches successful responses on disk according to the given configuration.
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.config.cache = Some(cache);
+        self
+    }
+
+    /// Records interactions to, or replays them from, the given cassette.
+    ///
+    /// Takes precedence over the cassette environment variables.
+    pub fn cassette(mut self, cassette: CassetteConfig) -> Self {
+        self.config.cassette = Some(cassette);
+        self
+    }
+
+    /// Sets how search result pages are parsed.
+    pub fn parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.config.parse_mode = parse_mode;
+        self
+    }
+
+    /// Impersonates the given app identity.
+    ///
+    /// Takes precedence over the identity environment variables.
+    pub fn identity(mut self, identity: AppIdentity) -> Self {
+        self.config.identity = Some(identity);
+        self
+    }
+
+    /// Uses a preconfigured `reqwest::Client` instead of building one.
+    ///
+    /// Authentication headers, the timeout and the retry policy are still applied
+    /// on top of the injected client.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Sends requests through a custom [`Transport`] instead of `reqwest`.
+    ///
+    /// Requests still carry the authentication headers, the `X-App-Id` and the
+    /// timeout, but the middleware layers (retries, rate limiting, cache and
+    /// cassette) are part of the default transport and are not applied. Cannot be
+    /// combined with [`HomegateClientBuilder::http_client`] or a proxy.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Reads the current time from the given clock instead of the system clock.
+    ///
+    /// The clock drives the `X-App-Id` header, cache expiry and the daily budget
+    /// roll-over. Pass a clone of a [`MockClock`](crate::clock::MockClock) to control
+    /// time in tests.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Builds the [`HomegateClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend URL or proxy URL is invalid, if a header
+    /// name or value is invalid, if a proxy is combined with an injected client, if
+    /// a custom transport is combined with either, or if the rate limit, cassette
+    /// or app identity configuration is invalid.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn build(self) -> crate::Result<HomegateClient> {
+        tracing::debug!("Building HTTP client with retry and app ID middleware");
+        let config = self.config;
+
+        let backend_url = config.backend_url.trim_end_matches('/').to_string();
+        Url::parse(&backend_url)?;
+
+        let identity = match &config.identity {
+            Some(identity) => identity.clone(),
+            None => AppIdentity::from_env()?,
+        };
+
+        let clock = self.clock.unwrap_or_else(|| Arc::new(SystemClock));
+
+        let transport: Arc<dyn Transport> = match self.transport {
+            Some(transport) => {
+                if self.http_client.is_some() || config.proxy.is_some() {
+                    return Err(crate::HomegateError::ValidationError(
+                        "a custom transport cannot be combined with an injected HTTP client or a proxy"
+                            .to_string(),
+                    ));
+                }
+                transport
+            }
+            None => Arc::new(middleware_client(
+                &config,
+                &identity,
+                &clock,
+                self.http_client,
+            )?),
+        };
+
+        Ok(HomegateClient {
+            transport,
+            clock,
+            backend_url,
+            headers: build_headers(&identity, &config.extra_headers)?,
+            identity: Arc::new(identity),
+            timeout: config.timeout,
+            parse_mode: config.parse_mode,
+        })
+    }
+}
+
+/// Builds the default transport: a `reqwest` client wrapped in the middleware
+/// layers selected by the configuration.
+///
+/// # Arguments
+///
+/// * `config` - Client configuration
+/// * `identity` - App identity used to sign the `X-App-Id` header
+/// * `clock` - Clock shared by the time-based middleware layers
+/// * `http_client` - Preconfigured `reqwest::Client` to wrap, if any
+fn middleware_client(
+    config: &HomegateConfig,
+    identity: &AppIdentity,
+    clock: &Arc<dyn Clock>,
+    http_client: Option<Client>,
+) -> crate::Result<ClientWithMiddleware> {
+    let client = match http_client {
+        Some(client) => {
+            if config.proxy.is_some() {
+                return Err(crate::HomegateError::ValidationError(
+                    "a proxy cannot be combined with an injected HTTP client".to_string(),
+                ));
+            }
+            client
+        }
+        None => {
+            let mut client_builder = Client::builder();
+            if let Some(proxy) = &config.proxy {
+                client_builder = client_builder.proxy(Proxy::all(proxy)?);
+            }
+            client_builder.build()?
+        }
+    };
+
+    // Configure exponential backoff retry policy
+    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(config.max_retries);
+    let retry_middleware = RetryTransientMiddleware::new_with_policy(retry_policy);
+
+    // The cassette runs first so that replays never reach the network, followed by
+    // the cache so that hits skip retries and rate limiting. The rate limiter and
+    // app ID middleware run after the retry middleware so that every attempt is
+    // throttled and carries an up-to-date X-App-Id header.
+    let mut client = MiddlewareClientBuilder::new(client);
+    let cassette = match &config.cassette {
+        Some(cassette) => Some(cassette.clone()),
+        None => CassetteConfig::from_env()?,
+    };
+    if let Some(cassette) = cassette {
+        client = client.with(Cassette::new(cassette)?);
+    }
+    if let Some(cache) = &config.cache {
+        client = client.with(ResponseCache::new(cache.clone()).clock(clock.clone()));
+    }
+    let mut client = client.with(retry_middleware);
+    if let Some(rate_limit) = &config.rate_limit {
+        client = client.with(RateLimiter::new(rate_limit)?.clock(clock.clone()));
+    }
+    Ok(client
+        .with(
+            AppIdMiddleware::new()
+                .identity(identity.clone())
+                .clock(clock.clone()),
+        )
+        .build())
+}
+
+/// Builds the headers required by the Homegate API.
+///
+/// This includes Basic Auth, app version and user agent, followed by any extra
+/// headers from the configuration. The time-based `X-App-Id` header is added per
+/// request by [`AppIdMiddleware`].
 ///
 /// # Arguments
 ///
-/// * `max_retries` - Maximum number of retry attempts for transient errors
+/// * `identity` - App identity providing the credentials, version and user agent
+/// * `extra_headers` - Additional `(name, value)` pairs to append
 ///
 /// # Returns
 ///
-/// Returns a configured `ClientWithMiddleware` on success, or an `Error` if client construction fails.
-#[tracing::instrument(level = "debug")]
-fn build_client(max_retries: u32) -> crate::Result<ClientWithMiddleware> {
-    tracing::debug!("Building HTTP client with retry middleware");
-    let client_builder: ClientBuilder = Client::builder();
-    let mut default_headers = header::HeaderMap::new();
-
-    let key = general_purpose::STANDARD.encode(format!("{}:{}", API_USERNAME, API_PASSWORD));
-    let app_id = calculate_app_id(&Utc::now().naive_utc());
+/// Returns the `HeaderMap` on success, or an `Error` if a header is invalid.
+fn build_headers(
+    identity: &AppIdentity,
+    extra_headers: &[(String, String)],
+) -> crate::Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
 
     const APPL_JSON: &str = "application/json";
 
-    default_headers.insert(
+    headers.insert(
         header::AUTHORIZATION,
-        HeaderValue::from_str(&format!("Basic {}", key))
+        HeaderValue::from_str(&identity.authorization())
             .map_err(|e| crate::HomegateError::InvalidHeader(e.to_string()))?,
     );
-    default_headers.insert(header::ACCEPT, HeaderValue::from_static(APPL_JSON));
-    default_headers.insert(
-        "X-App-Id",
-        app_id.parse().map_err(|e: header::InvalidHeaderValue| {
-            crate::HomegateError::InvalidHeader(e.to_string())
-        })?,
-    );
-    default_headers.insert(
+    headers.insert(header::ACCEPT, HeaderValue::from_static(APPL_JSON));
+    headers.insert(
         "X-App-Version",
-        app_version()
+        identity
+            .app_version()
             .parse()
             .map_err(|e: header::InvalidHeaderValue| {
                 crate::HomegateError::InvalidHeader(e.to_string())
             })?,
     );
-    default_headers.insert(header::USER_AGENT, HeaderValue::from_static(USER_AGENT)); // Not a typo!
-    default_headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(APPL_JSON));
-
-    let client = client_builder.default_headers(default_headers).build()?;
-
-    // Configure exponential backoff retry policy
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(max_retries);
-    let retry_middleware = RetryTransientMiddleware::new_with_policy(retry_policy);
+    headers.insert(
+        header::USER_AGENT,
+        HeaderValue::from_str(&identity.user_agent) // Not a typo!
+            .map_err(|e| crate::HomegateError::InvalidHeader(e.to_string()))?,
+    );
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(APPL_JSON));
 
-    let client_with_middleware = MiddlewareClientBuilder::new(client)
-        .with(retry_middleware)
-        .build();
+    for (name, value) in extra_headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| crate::HomegateError::InvalidHeader(format!("{}: {}", name, e)))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|e| crate::HomegateError::InvalidHeader(format!("{}: {}", name, e)))?;
+        headers.insert(name, value);
+    }
 
-    Ok(client_with_middleware)
+    Ok(headers)
 }
 
 /// Sends an authenticated POST request to the specified URL.
@@ -277,7 +903,5 @@ fn build_client(max_retries: u32) -> crate::Result<ClientWithMiddleware> {
 #[tracing::instrument(level = "info", skip(body), fields(url = %url))]
 pub async fn post_url(url: Url, body: &str) -> crate::Result<Response> {
     tracing::info!("Sending POST request");
-    let c = build_client(3)?; // Default 3 retries
-    let req = c.post(url).body(body.to_string()).build()?;
-    c.execute(req).await.map_err(Into::into)
+    HomegateClient::new()?.post_url(url, body).await
 }
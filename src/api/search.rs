@@ -3,12 +3,15 @@
 //! This module provides search request structures and functions for querying
 //! the Homegate API for real estate listings based on various criteria.
 
+use std::fmt;
+use std::str::FromStr;
 use std::sync::LazyLock;
 
 use serde::{Deserialize, Serialize};
 
 use crate::api::request::HomegateClient;
 use crate::models::listing::Category;
+use crate::models::location::Location as GeoLocation;
 use crate::models::paginated::Paginated;
 use crate::models::realestate::{OfferType, RealEstate};
 
@@ -16,7 +19,7 @@ use crate::models::realestate::{OfferType, RealEstate};
 ///
 /// Used to specify minimum and maximum values for search criteria like price,
 /// living space, or number of rooms.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
 pub struct FromTo {
     /// Minimum value (inclusive)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,6 +30,11 @@ pub struct FromTo {
 }
 
 impl FromTo {
+    /// Returns `true` if neither bound is set.
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
     /// Validates that the range is valid (from <= to if both are specified).
     ///
     /// # Returns
@@ -141,14 +149,181 @@ pub struct Query {
     pub exclude_categories: Vec<String>,
     /// Living space filter in square meters
     pub living_space: FromTo,
-    /// Geographic location and search radius
-    pub location: Location,
-    /// Monthly rent filter in CHF
+    /// Area to search in
+    pub location: SearchLocation,
+    /// Monthly rent filter in CHF (only applies to `RENT` offers)
+    #[serde(default, skip_serializing_if = "FromTo::is_empty")]
     pub monthly_rent: FromTo,
     /// Number of rooms filter (supports fractional values like 2.5, 3.5)
     pub number_of_rooms: FromToFloat,
     /// Type of offer (RENT, BUY, etc.)
     pub offer_type: OfferType,
+    /// Purchase price filter in CHF (only applies to `BUY` offers)
+    #[serde(default, skip_serializing_if = "FromTo::is_empty")]
+    pub purchase_price: FromTo,
+}
+
+/// Area to search in.
+///
+/// Either a circle around coordinates, or one or more geo tags (cities, postal codes,
+/// districts) as resolved by [`HomegateClient::geo_lookup`].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum SearchLocation {
+    /// Circular area around a point
+    Radius(Location),
+    /// Named areas identified by their geo tags (e.g., "geo-city-winterthur")
+    GeoTags {
+        /// Geo tag identifiers
+        #[serde(rename = "geoTags")]
+        geo_tags: Vec<String>,
+    },
+}
+
+impl SearchLocation {
+    /// Returns the circular area, if this is a radius search.
+    pub fn as_radius(&self) -> Option<&Location> {
+        match self {
+            SearchLocation::Radius(location) => Some(location),
+            SearchLocation::GeoTags { .. } => None,
+        }
+    }
+
+    /// Validates the search location.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if valid, or an error message if invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            SearchLocation::Radius(location) => location.validate(),
+            SearchLocation::GeoTags { geo_tags } if geo_tags.is_empty() => {
+                Err("Invalid geo tags: at least one is required".to_string())
+            }
+            SearchLocation::GeoTags { .. } => Ok(()),
+        }
+    }
+}
+
+impl From<Location> for SearchLocation {
+    fn from(location: Location) -> Self {
+        SearchLocation::Radius(location)
+    }
+}
+
+impl From<&GeoLocation> for SearchLocation {
+    fn from(location: &GeoLocation) -> Self {
+        SearchLocation::GeoTags {
+            geo_tags: vec![location.id().to_string()],
+        }
+    }
+}
+
+/// Field to sort search results by.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::search::{SortBy, SortDirection};
+///
+/// let sort: SortBy = "newest".parse().unwrap();
+/// assert_eq!(sort, SortBy::PublicationDate);
+/// assert_eq!(sort.default_direction(), SortDirection::Desc);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SortBy {
+    /// Relevance, i.e. listing tier (premium listings first when descending)
+    #[default]
+    #[serde(rename = "listingType")]
+    ListingType,
+    /// Rent or purchase price
+    #[serde(rename = "price")]
+    Price,
+    /// Living space
+    #[serde(rename = "livingSpace")]
+    LivingSpace,
+    /// Number of rooms
+    #[serde(rename = "numberOfRooms")]
+    NumberOfRooms,
+    /// Publication date (newest first when descending)
+    #[serde(rename = "dateCreated")]
+    PublicationDate,
+    /// Distance from the search location
+    #[serde(rename = "distance")]
+    Distance,
+}
+
+impl SortBy {
+    /// Returns the direction that makes the most sense for this field.
+    ///
+    /// Relevance and publication date sort descending (best and newest first), all
+    /// other fields ascending (cheapest, smallest and closest first).
+    pub fn default_direction(&self) -> SortDirection {
+        match self {
+            SortBy::ListingType | SortBy::PublicationDate => SortDirection::Desc,
+            _ => SortDirection::Asc,
+        }
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Serialize::serialize(self, f)
+    }
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    /// Parses a sort field case-insensitively, accepting the API names as well as
+    /// aliases such as `relevance`, `newest`, `space` or `rooms`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "relevance" | "listing-type" | "listingtype" => Ok(SortBy::ListingType),
+            "price" => Ok(SortBy::Price),
+            "living-space" | "livingspace" | "space" => Ok(SortBy::LivingSpace),
+            "number-of-rooms" | "numberofrooms" | "rooms" => Ok(SortBy::NumberOfRooms),
+            "publication-date" | "datecreated" | "date" | "newest" => Ok(SortBy::PublicationDate),
+            "distance" => Ok(SortBy::Distance),
+            other => Err(format!(
+                "Unknown sort field: {}. Supported: relevance, price, living-space, rooms, newest, distance",
+                other
+            )),
+        }
+    }
+}
+
+/// Direction to sort search results in.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    /// Ascending (smallest first)
+    Asc,
+    /// Descending (largest first)
+    #[default]
+    Desc,
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Serialize::serialize(self, f)
+    }
+}
+
+impl FromStr for SortDirection {
+    type Err = String;
+
+    /// Parses `asc` or `desc` case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "asc" => Ok(SortDirection::Asc),
+            "desc" => Ok(SortDirection::Desc),
+            other => Err(format!(
+                "Unknown sort direction: {}. Supported: asc, desc",
+                other
+            )),
+        }
+    }
 }
 
 /// Template for geographic coordinate fields in search results.
@@ -266,9 +441,9 @@ pub struct SearchRequest {
     /// Number of results per page
     pub size: i32,
     /// Field to sort results by
-    pub sort_by: String,
-    /// Sort direction ("asc" or "desc")
-    pub sort_direction: String,
+    pub sort_by: SortBy,
+    /// Sort direction
+    pub sort_direction: SortDirection,
     /// Whether to track total number of hits
     pub track_total_hits: bool,
 }
@@ -279,39 +454,41 @@ const LT: LocaleTemplate = LocaleTemplate {
     text: LocaleTextTemplate { title: true },
 };
 
+/// Maximum number of results per page accepted by [`SearchQuery`].
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// All apartment and house categories searched by default.
+const ALL_CATEGORIES: [Category; 25] = [
+    Category::Apartment,
+    Category::Maisonette,
+    Category::Duplex,
+    Category::AtticFlat,
+    Category::RoofFlat,
+    Category::Studio,
+    Category::SingleRoom,
+    Category::TerraceFlat,
+    Category::BachelorFlat,
+    Category::Loft,
+    Category::Attic,
+    Category::RowHouse,
+    Category::BifamiliarHouse,
+    Category::TerraceHouse,
+    Category::Villa,
+    Category::FarmHouse,
+    Category::CaveHouse,
+    Category::Castle,
+    Category::GrannyFlat,
+    Category::Chalet,
+    Category::Rustico,
+    Category::SingleHouse,
+    Category::HobbyRoom,
+    Category::CellarCompartment,
+    Category::AtticCompartment,
+];
+
 /// Cached default categories to avoid allocations on each call to `default_search()`.
-static DEFAULT_CATEGORIES: LazyLock<Vec<String>> = LazyLock::new(|| {
-    [
-        Category::Apartment,
-        Category::Maisonette,
-        Category::Duplex,
-        Category::AtticFlat,
-        Category::RoofFlat,
-        Category::Studio,
-        Category::SingleRoom,
-        Category::TerraceFlat,
-        Category::BachelorFlat,
-        Category::Loft,
-        Category::Attic,
-        Category::RowHouse,
-        Category::BifamiliarHouse,
-        Category::TerraceHouse,
-        Category::Villa,
-        Category::FarmHouse,
-        Category::CaveHouse,
-        Category::Castle,
-        Category::GrannyFlat,
-        Category::Chalet,
-        Category::Rustico,
-        Category::SingleHouse,
-        Category::HobbyRoom,
-        Category::CellarCompartment,
-        Category::AtticCompartment,
-    ]
-    .iter()
-    .map(|c| c.to_string())
-    .collect()
-});
+static DEFAULT_CATEGORIES: LazyLock<Vec<String>> =
+    LazyLock::new(|| ALL_CATEGORIES.iter().map(|c| c.to_string()).collect());
 
 /// Cached default excluded categories.
 static DEFAULT_EXCLUDE_CATEGORIES: LazyLock<Vec<String>> = LazyLock::new(|| {
@@ -347,7 +524,8 @@ static DEFAULT_EXCLUDE_CATEGORIES: LazyLock<Vec<String>> = LazyLock::new(|| {
 ///     latitude: 47.36667,
 ///     longitude: 8.55,
 ///     radius: 1000,
-/// };
+/// }
+/// .into();
 /// ```
 pub fn default_search() -> SearchRequest {
     SearchRequest {
@@ -359,11 +537,11 @@ pub fn default_search() -> SearchRequest {
                 from: Some(60),
                 to: None,
             },
-            location: Location {
+            location: SearchLocation::Radius(Location {
                 latitude: 47.359_856,
                 longitude: 8.541_819,
                 radius: 622,
-            },
+            }),
             monthly_rent: FromTo {
                 from: Some(500),
                 to: None,
@@ -373,6 +551,7 @@ pub fn default_search() -> SearchRequest {
                 to: None,
             },
             offer_type: OfferType::RENT,
+            purchase_price: FromTo::default(),
         },
         result_template: ResultTemplate {
             id: true,
@@ -418,12 +597,336 @@ pub fn default_search() -> SearchRequest {
             remote_viewing: true,
         },
         size: 20,
-        sort_by: String::from("listingType"),
-        sort_direction: String::from("desc"),
+        sort_by: SortBy::ListingType,
+        sort_direction: SortDirection::Desc,
         track_total_hits: true,
     }
 }
 
+/// Typed builder for search requests.
+///
+/// Unlike [`default_search`], a `SearchQuery` applies no hidden filters: only the
+/// criteria set through the builder methods are sent. All parameters are checked
+/// together by [`SearchQuery::validate`], so every problem is reported at once.
+///
+/// # Defaults
+///
+/// - Categories: all apartment and house types
+/// - Excluded categories: none
+/// - Living space, prices and number of rooms: unrestricted
+/// - Offer type: `RENT`
+/// - Sort: by relevance (listing type), descending
+/// - Page 1 with 20 results per page
+///
+/// # Examples
+///
+/// ```no_run
+/// use homegate::api::request::HomegateClient;
+/// use homegate::api::search::{Location, SearchQuery};
+/// use homegate::models::listing::Category;
+///
+/// # async fn example() -> homegate::Result<()> {
+/// let query = SearchQuery::new(Location {
+///     latitude: 47.36667,
+///     longitude: 8.55,
+///     radius: 2000,
+/// })
+/// .categories([Category::Apartment, Category::Loft])
+/// .monthly_rent(Some(1000), Some(2500))
+/// .number_of_rooms(Some(3.0), None)
+/// .page_size(50);
+///
+/// let client = HomegateClient::new()?;
+/// let results = client.search_with(&query).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchQuery {
+    location: SearchLocation,
+    categories: Vec<Category>,
+    exclude_categories: Vec<Category>,
+    living_space: FromTo,
+    monthly_rent: FromTo,
+    number_of_rooms: FromToFloat,
+    offer_type: OfferType,
+    purchase_price: FromTo,
+    sort_by: SortBy,
+    sort_direction: SortDirection,
+    page: u32,
+    page_size: u32,
+}
+
+impl SearchQuery {
+    /// Creates a query for the given location without any additional filters.
+    ///
+    /// Accepts either a [`Location`] (coordinates and radius) or a
+    /// [`GeoLocation`] returned by [`HomegateClient::geo_lookup`].
+    pub fn new(location: impl Into<SearchLocation>) -> Self {
+        Self {
+            location: location.into(),
+            categories: ALL_CATEGORIES.to_vec(),
+            exclude_categories: Vec::new(),
+            living_space: FromTo::default(),
+            monthly_rent: FromTo::default(),
+            number_of_rooms: FromToFloat {
+                from: None,
+                to: None,
+            },
+            offer_type: OfferType::RENT,
+            purchase_price: FromTo::default(),
+            sort_by: SortBy::ListingType,
+            sort_direction: SortDirection::Desc,
+            page: 1,
+            page_size: 20,
+        }
+    }
+
+    /// Replaces the search location.
+    pub fn location(mut self, location: impl Into<SearchLocation>) -> Self {
+        self.location = location.into();
+        self
+    }
+
+    /// Restricts the search to the given property categories.
+    pub fn categories(mut self, categories: impl IntoIterator<Item = Category>) -> Self {
+        self.categories = categories.into_iter().collect();
+        self
+    }
+
+    /// Excludes the given property categories from the search.
+    pub fn exclude_categories(mut self, categories: impl IntoIterator<Item = Category>) -> Self {
+        self.exclude_categories = categories.into_iter().collect();
+        self
+    }
+
+    /// Sets the living space range in square meters.
+    pub fn living_space(mut self, from: Option<u32>, to: Option<u32>) -> Self {
+        self.living_space = FromTo { from, to };
+        self
+    }
+
+    /// Sets the monthly rent range in CHF (for `RENT` offers).
+    pub fn monthly_rent(mut self, from: Option<u32>, to: Option<u32>) -> Self {
+        self.monthly_rent = FromTo { from, to };
+        self
+    }
+
+    /// Sets the purchase price range in CHF (for `BUY` offers).
+    pub fn purchase_price(mut self, from: Option<u32>, to: Option<u32>) -> Self {
+        self.purchase_price = FromTo { from, to };
+        self
+    }
+
+    /// Sets the price range matching the current offer type.
+    ///
+    /// This is the monthly rent for `RENT` offers and the purchase price for `BUY`
+    /// offers, so it must be called after [`SearchQuery::offer_type`]. It has no
+    /// effect for unknown offer types.
+    pub fn price(self, from: Option<u32>, to: Option<u32>) -> Self {
+        match self.offer_type {
+            OfferType::RENT => self.monthly_rent(from, to),
+            OfferType::BUY => self.purchase_price(from, to),
+            OfferType::Unknown(_) => self,
+        }
+    }
+
+    /// Sets the number of rooms range (supports fractional values like 2.5).
+    pub fn number_of_rooms(mut self, from: Option<f32>, to: Option<f32>) -> Self {
+        self.number_of_rooms = FromToFloat { from, to };
+        self
+    }
+
+    /// Sets the offer type.
+    pub fn offer_type(mut self, offer_type: OfferType) -> Self {
+        self.offer_type = offer_type;
+        self
+    }
+
+    /// Sets the field to sort by and the sort direction.
+    pub fn sort(mut self, sort_by: SortBy, direction: SortDirection) -> Self {
+        self.sort_by = sort_by;
+        self.sort_direction = direction;
+        self
+    }
+
+    /// Sets the page to fetch (1-indexed).
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the number of results per page.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Validates all parameters of the query.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HomegateError::ValidationError` listing every invalid parameter,
+    /// separated by `; `.
+    pub fn validate(&self) -> crate::Result<()> {
+        let mut errors = Vec::new();
+
+        if let Err(e) = self.location.validate() {
+            errors.push(format!("location: {}", e));
+        }
+        if let Err(e) = self.living_space.validate() {
+            errors.push(format!("living_space: {}", e));
+        }
+        if let Err(e) = self.monthly_rent.validate() {
+            errors.push(format!("monthly_rent: {}", e));
+        }
+        if let Err(e) = self.number_of_rooms.validate() {
+            errors.push(format!("number_of_rooms: {}", e));
+        }
+        if let Err(e) = self.purchase_price.validate() {
+            errors.push(format!("purchase_price: {}", e));
+        }
+        match self.offer_type {
+            OfferType::RENT if !self.purchase_price.is_empty() => {
+                errors.push("purchase_price: only applies to BUY offers".to_string());
+            }
+            OfferType::BUY if !self.monthly_rent.is_empty() => {
+                errors.push("monthly_rent: only applies to RENT offers".to_string());
+            }
+            _ => {}
+        }
+        if self.categories.is_empty() {
+            errors.push("categories: at least one category is required".to_string());
+        }
+        if let Some(c) = self
+            .categories
+            .iter()
+            .find(|c| self.exclude_categories.contains(c))
+        {
+            errors.push(format!("categories: {} is both included and excluded", c));
+        }
+        if self.page == 0 {
+            errors.push("page: must be greater than 0".to_string());
+        }
+        if self.page_size == 0 || self.page_size > MAX_PAGE_SIZE {
+            errors.push(format!(
+                "page_size: {} (must be between 1 and {})",
+                self.page_size, MAX_PAGE_SIZE
+            ));
+        }
+        if self.page > 0 && first_result_index(self.page, self.page_size).is_none() {
+            errors.push(format!(
+                "page: {} is too large for a page size of {}",
+                self.page, self.page_size
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::HomegateError::ValidationError(errors.join("; ")))
+        }
+    }
+
+    /// Validates the query and converts it into a [`SearchRequest`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HomegateError::ValidationError` if [`SearchQuery::validate`] fails.
+    pub fn to_request(&self) -> crate::Result<SearchRequest> {
+        self.validate()?;
+
+        let mut request = default_search();
+        request.query = Query {
+            categories: self.categories.iter().map(|c| c.to_string()).collect(),
+            exclude_categories: self
+                .exclude_categories
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            living_space: self.living_space.clone(),
+            location: self.location.clone(),
+            monthly_rent: self.monthly_rent.clone(),
+            number_of_rooms: self.number_of_rooms.clone(),
+            offer_type: self.offer_type.clone(),
+            purchase_price: self.purchase_price.clone(),
+        };
+        request.from = first_result_index(self.page, self.page_size).ok_or_else(|| {
+            crate::HomegateError::ValidationError(format!("page: {} is too large", self.page))
+        })?;
+        request.size = i32::try_from(self.page_size).map_err(|_| {
+            crate::HomegateError::ValidationError(format!(
+                "page_size: {} is too large",
+                self.page_size
+            ))
+        })?;
+        request.sort_by = self.sort_by;
+        request.sort_direction = self.sort_direction;
+
+        Ok(request)
+    }
+
+    /// Returns the page numbers following this query's page, given its first result page.
+    ///
+    /// Pages are generated while their starting index stays within `max_from` and
+    /// `total`, and only as many as needed to reach `limit` results.
+    pub(crate) fn following_pages<T>(
+        &self,
+        first: &Paginated<T>,
+        limit: Option<usize>,
+    ) -> Vec<u32> {
+        let page_size = self.page_size.max(1);
+        let last_from = first.max_from.min(first.total.saturating_sub(1));
+        let max_pages = limit.map(|l| l.div_ceil(page_size as usize).saturating_sub(1));
+
+        (self.page + 1..)
+            .take_while(|&page| {
+                first.total > 0
+                    && first_result_index(page, page_size)
+                        .is_some_and(|from| from as u32 <= last_from)
+            })
+            .take(max_pages.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+/// Returns the index of the first result on a page (1-indexed), as sent in the
+/// `from` field of a [`SearchRequest`], or `None` if it does not fit in an `i32`.
+fn first_result_index(page: u32, page_size: u32) -> Option<i32> {
+    let from = page.checked_sub(1)?.checked_mul(page_size)?;
+    i32::try_from(from).ok()
+}
+
+/// Options for [`HomegateClient::search_all`].
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::search::SearchAllOptions;
+///
+/// let options = SearchAllOptions {
+///     limit: Some(100),
+///     concurrency: 2,
+/// };
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchAllOptions {
+    /// Maximum number of listings to yield (`None` for every result)
+    pub limit: Option<usize>,
+    /// Maximum number of pages fetched concurrently ahead of the consumer
+    pub concurrency: usize,
+}
+
+impl Default for SearchAllOptions {
+    /// Creates options without a limit, fetching one page at a time.
+    fn default() -> Self {
+        Self {
+            limit: None,
+            concurrency: 1,
+        }
+    }
+}
+
 /// Searches for real estate listings at the specified location.
 ///
 /// Performs a search using default parameters with the provided location.
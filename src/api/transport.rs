@@ -0,0 +1,71 @@
+//! Pluggable transport used by [`HomegateClient`](crate::api::request::HomegateClient).
+//!
+//! The client builds fully authenticated `reqwest` requests and hands them to a
+//! [`Transport`] to be sent. By default this is a `reqwest-middleware` client with
+//! the retry, rate limit, cache, cassette and app ID layers, but any implementation
+//! can be injected with
+//! [`HomegateClientBuilder::transport`](crate::api::request::HomegateClientBuilder::transport),
+//! e.g. an in-memory fake in unit tests.
+
+use std::fmt;
+
+use reqwest::{Request, Response};
+use reqwest_middleware::ClientWithMiddleware;
+
+/// Sends HTTP requests on behalf of a
+/// [`HomegateClient`](crate::api::request::HomegateClient).
+///
+/// Requests already carry the authentication headers, the time-based `X-App-Id`
+/// header and the timeout, so transports only need to send them.
+///
+/// Responses are returned as `reqwest::Response`, which can be built from any
+/// `http::Response` with `reqwest::Response::from`. Non-success statuses should be
+/// returned as responses, not errors, so that the client can classify them.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::api::request::HomegateClient;
+/// use homegate::api::transport::Transport;
+/// use reqwest::{Request, Response};
+///
+/// #[derive(Debug)]
+/// struct NoResults;
+///
+/// #[async_trait::async_trait]
+/// impl Transport for NoResults {
+///     async fn send(&self, _request: Request) -> homegate::Result<Response> {
+///         let body = r#"{"from":0,"maxFrom":0,"size":0,"total":0,"results":[]}"#;
+///         Ok(Response::from(http::Response::new(body)))
+///     }
+/// }
+///
+/// # async fn example() -> homegate::Result<()> {
+/// let client = HomegateClient::builder().transport(NoResults).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends a request and returns the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no response could be obtained, e.g. on connection
+    /// failures or timeouts.
+    async fn send(&self, request: Request) -> crate::Result<Response>;
+}
+
+#[async_trait::async_trait]
+impl Transport for ClientWithMiddleware {
+    async fn send(&self, request: Request) -> crate::Result<Response> {
+        Ok(self.execute(request).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for reqwest::Client {
+    async fn send(&self, request: Request) -> crate::Result<Response> {
+        Ok(self.execute(request).await?)
+    }
+}
@@ -0,0 +1,94 @@
+//! Injectable source of the current time.
+//!
+//! The `X-App-Id` header, cache expiry and the daily request budget all depend on
+//! the wall-clock time. They read it through a [`Clock`], so tests can pin or
+//! advance time with a [`MockClock`] instead of waiting for it to pass.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current time.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::clock::{Clock, SystemClock};
+///
+/// let now = SystemClock.now();
+/// assert!(now.timestamp() > 0);
+/// ```
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock whose time only changes when told to.
+///
+/// Clones share the same time, so a test can keep one clone and advance the time
+/// seen by a client it passed the other to.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use homegate::clock::{Clock, MockClock};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let clock = MockClock::new(start);
+/// let shared = clock.clone();
+///
+/// clock.advance(Duration::minutes(1));
+/// assert_eq!(shared.now(), start + Duration::minutes(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// Creates a clock frozen at the given time.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    /// Moves the current time forward, or backward for a negative duration.
+    pub fn advance(&self, by: chrono::Duration) {
+        *self.lock() += by;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for MockClock {
+    /// Creates a clock frozen at the current system time.
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
@@ -1,8 +1,11 @@
 //! Configuration options for the Homegate API client.
 //!
 //! This module provides configuration structures for customizing client behavior,
-//! including backend URL, timeouts, and retry settings.
+//! including backend URL, timeouts, retry settings, proxy, extra headers,
+//! client-side rate limiting, response caching and cassette record/replay.
 
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::Duration;
 
 /// Configuration for the Homegate API client.
@@ -24,6 +27,7 @@ use std::time::Duration;
 ///     backend_url: "https://api.homegate.ch".to_string(),
 ///     timeout: Duration::from_secs(60),
 ///     max_retries: 5,
+///     ..HomegateConfig::default()
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -42,6 +46,49 @@ pub struct HomegateConfig {
     /// When a request fails due to transient errors (like network issues or 5xx responses),
     /// the client will automatically retry up to this many times using exponential backoff.
     pub max_retries: u32,
+
+    /// Optional proxy URL all requests are routed through
+    ///
+    /// Accepts any URL supported by [`reqwest::Proxy::all`], e.g. `http://127.0.0.1:8080`
+    /// or `socks5://127.0.0.1:1080`.
+    pub proxy: Option<String>,
+
+    /// Additional headers sent with every request
+    ///
+    /// These are applied after the authentication headers, so they can also be used
+    /// to override one of them.
+    pub extra_headers: Vec<(String, String)>,
+
+    /// Optional client-side rate limiting
+    ///
+    /// When set, every request (including retries) waits for a token from a
+    /// [`RateLimiter`](crate::api::rate_limit::RateLimiter) shared by all clones of the client.
+    pub rate_limit: Option<RateLimitConfig>,
+
+    /// Optional on-disk response cache
+    ///
+    /// When set, successful responses are stored by a
+    /// [`ResponseCache`](crate::api::cache::ResponseCache) and identical requests are
+    /// answered from disk until they expire.
+    pub cache: Option<CacheConfig>,
+
+    /// Optional cassette to record responses to or replay them from
+    ///
+    /// When unset, the [`CASSETTE_ENV`] and [`CASSETTE_MODE_ENV`] environment
+    /// variables are consulted when the client is built.
+    pub cassette: Option<CassetteConfig>,
+
+    /// Optional identity of the mobile app the client impersonates
+    ///
+    /// When unset, [`AppIdentity::from_env`](crate::api::app_id::AppIdentity::from_env)
+    /// is used when the client is built, which defaults to the Android app.
+    pub identity: Option<crate::api::app_id::AppIdentity>,
+
+    /// How search result pages are parsed
+    ///
+    /// The default [`ParseMode::Strict`] fails the whole page on a malformed
+    /// listing; [`ParseMode::Lenient`] skips it instead.
+    pub parse_mode: ParseMode,
 }
 
 impl Default for HomegateConfig {
@@ -52,11 +99,25 @@ impl Default for HomegateConfig {
     /// - `backend_url`: `https://api.homegate.ch`
     /// - `timeout`: 30 seconds
     /// - `max_retries`: 3
+    /// - `proxy`: none
+    /// - `extra_headers`: none
+    /// - `rate_limit`: none
+    /// - `cache`: none
+    /// - `cassette`: none
+    /// - `identity`: none
+    /// - `parse_mode`: strict
     fn default() -> Self {
         Self {
             backend_url: crate::api::BACKEND_URL.to_string(),
             timeout: Duration::from_secs(30),
             max_retries: 3,
+            proxy: None,
+            extra_headers: Vec::new(),
+            rate_limit: None,
+            cache: None,
+            cassette: None,
+            identity: None,
+            parse_mode: ParseMode::Strict,
         }
     }
 }
@@ -87,6 +148,7 @@ impl HomegateConfig {
             backend_url: backend_url.into(),
             timeout,
             max_retries,
+            ..Self::default()
         }
     }
 
@@ -103,6 +165,257 @@ impl HomegateConfig {
             backend_url: crate::api::BACKEND_URL.to_string(),
             timeout: Duration::from_secs(10),
             max_retries: 1,
+            ..Self::default()
+        }
+    }
+}
+
+/// Configuration for client-side rate limiting.
+///
+/// Requests are throttled with a token bucket refilled at `requests_per_second`
+/// and holding at most `burst` tokens. An optional daily budget caps the total
+/// number of requests per calendar day; storing it in `budget_file` lets several
+/// processes on the same machine share one budget.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::config::{HomegateConfig, RateLimitConfig};
+///
+/// let config = HomegateConfig {
+///     rate_limit: Some(RateLimitConfig {
+///         requests_per_second: 0.5,
+///         burst: 2,
+///         daily_budget: Some(500),
+///         budget_file: Some("/var/lib/homegate/budget.json".into()),
+///     }),
+///     ..HomegateConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitConfig {
+    /// Sustained number of requests allowed per second
+    pub requests_per_second: f64,
+
+    /// Maximum number of requests that may be sent back to back
+    pub burst: u32,
+
+    /// Maximum number of requests per local calendar day
+    ///
+    /// Once exhausted, requests fail with
+    /// [`HomegateError::BudgetExhausted`](crate::HomegateError::BudgetExhausted)
+    /// until the next day.
+    pub daily_budget: Option<u32>,
+
+    /// File the daily budget counter is persisted to
+    ///
+    /// The file is locked while it is updated, so it can be shared by several
+    /// processes. When unset, the counter is kept in memory only.
+    pub budget_file: Option<PathBuf>,
+}
+
+impl Default for RateLimitConfig {
+    /// Creates a default rate limit configuration.
+    ///
+    /// # Default Values
+    ///
+    /// - `requests_per_second`: 1
+    /// - `burst`: 5
+    /// - `daily_budget`: none
+    /// - `budget_file`: none
+    fn default() -> Self {
+        Self {
+            requests_per_second: 1.0,
+            burst: 5,
+            daily_budget: None,
+            budget_file: None,
+        }
+    }
+}
+
+/// Configuration for the on-disk response cache.
+///
+/// Entries are keyed on the HTTP method, the URL, the request headers except the
+/// time-based `X-App-Id`, and the canonicalized JSON request body. Two searches
+/// differing only in key order share an entry, while clients sending different
+/// headers or credentials (e.g. another app identity) never share entries.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::config::{CacheConfig, HomegateConfig};
+/// use std::time::Duration;
+///
+/// let config = HomegateConfig {
+///     cache: Some(CacheConfig {
+///         ttl: Duration::from_secs(3600),
+///         ..CacheConfig::default()
+///     }),
+///     ..HomegateConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheConfig {
+    /// Directory the cache entries are stored in
+    pub dir: PathBuf,
+
+    /// How long an entry is served before it is fetched again
+    pub ttl: Duration,
+
+    /// Maximum total size of the cache directory in bytes
+    ///
+    /// The oldest entries are evicted once a new entry pushes the cache over this size.
+    pub max_size_bytes: u64,
+
+    /// Ignore existing entries but still store fresh responses
+    pub refresh: bool,
+}
+
+impl Default for CacheConfig {
+    /// Creates a default cache configuration.
+    ///
+    /// # Default Values
+    ///
+    /// - `dir`: see [`default_cache_dir`]
+    /// - `ttl`: 15 minutes
+    /// - `max_size_bytes`: 50 MiB
+    /// - `refresh`: false
+    fn default() -> Self {
+        Self {
+            dir: default_cache_dir(),
+            ttl: Duration::from_secs(15 * 60),
+            max_size_bytes: 50 * 1024 * 1024,
+            refresh: false,
+        }
+    }
+}
+
+/// Returns the default cache directory.
+///
+/// This is `$XDG_CACHE_HOME/homegate`, falling back to `$HOME/.cache/homegate` and
+/// finally to a `homegate` directory in the system temporary directory.
+pub fn default_cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(|dir| !dir.is_empty())
+                .map(|home| PathBuf::from(home).join(".cache"))
+        })
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("homegate")
+}
+
+/// Environment variable holding the path of the cassette file.
+pub const CASSETTE_ENV: &str = "HOMEGATE_CASSETTE";
+
+/// Environment variable selecting the cassette mode (`record` or `replay`).
+pub const CASSETTE_MODE_ENV: &str = "HOMEGATE_CASSETTE_MODE";
+
+/// Configuration for recording or replaying HTTP interactions.
+///
+/// A cassette is a JSON file of request/response pairs. In [`CassetteMode::Record`]
+/// every interaction with the API is written to it; in [`CassetteMode::Replay`] the
+/// recorded responses are served without touching the network. Requests are matched
+/// on method, path (including the query string) and normalized JSON body.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::config::{CassetteConfig, CassetteMode, HomegateConfig};
+///
+/// let config = HomegateConfig {
+///     cassette: Some(CassetteConfig {
+///         path: "tests/cassettes/search.json".into(),
+///         mode: CassetteMode::Replay,
+///     }),
+///     ..HomegateConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CassetteConfig {
+    /// Path of the cassette file
+    pub path: PathBuf,
+
+    /// Whether to record new interactions or replay existing ones
+    pub mode: CassetteMode,
+}
+
+impl CassetteConfig {
+    /// Reads the cassette configuration from the environment.
+    ///
+    /// [`CASSETTE_ENV`] holds the path of the cassette and [`CASSETTE_MODE_ENV`]
+    /// the mode, which defaults to replay.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if [`CASSETTE_ENV`] is unset or empty, or a `ValidationError` if
+    /// the mode is not recognized.
+    pub fn from_env() -> crate::Result<Option<Self>> {
+        let path = match std::env::var_os(CASSETTE_ENV) {
+            Some(path) if !path.is_empty() => PathBuf::from(path),
+            _ => return Ok(None),
+        };
+        let mode = match std::env::var(CASSETTE_MODE_ENV) {
+            Ok(mode) if !mode.is_empty() => mode
+                .parse()
+                .map_err(crate::HomegateError::ValidationError)?,
+            _ => CassetteMode::default(),
+        };
+        Ok(Some(Self { path, mode }))
+    }
+}
+
+/// How search result pages are parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Skip results that cannot be parsed and report them in
+    /// [`Paginated::skipped`](crate::models::paginated::Paginated::skipped)
+    Lenient,
+    /// Fail the whole page if any result cannot be parsed
+    #[default]
+    Strict,
+}
+
+impl FromStr for ParseMode {
+    type Err = String;
+
+    /// Parses `lenient` or `strict` case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "lenient" => Ok(ParseMode::Lenient),
+            "strict" => Ok(ParseMode::Strict),
+            other => Err(format!(
+                "Unknown parse mode: {}. Supported: lenient, strict",
+                other
+            )),
+        }
+    }
+}
+
+/// Mode of a cassette.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the API and write every interaction to the cassette
+    Record,
+    /// Serve recorded responses without network access
+    #[default]
+    Replay,
+}
+
+impl FromStr for CassetteMode {
+    type Err = String;
+
+    /// Parses `record` or `replay` case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            other => Err(format!(
+                "Unknown cassette mode: {}. Supported: record, replay",
+                other
+            )),
         }
     }
 }
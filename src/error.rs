@@ -1,4 +1,13 @@
 use std::fmt;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest_retry::RetryError;
+
+use crate::api::rate_limit::RateLimitError;
+
+/// Maximum number of characters of a response body kept in status errors.
+const MAX_ERROR_BODY_CHARS: usize = 512;
 
 /// Custom error type for the Homegate library.
 ///
@@ -18,6 +27,111 @@ pub enum HomegateError {
     InvalidUrl(url::ParseError),
     /// Input validation failed
     ValidationError(String),
+    /// The API rejected the credentials or app identification (401 or 403)
+    Unauthorized {
+        /// HTTP status code
+        status: u16,
+        /// Response body, truncated
+        body: String,
+    },
+    /// The API is throttling requests (429)
+    RateLimited {
+        /// Delay requested by the `Retry-After` header, if present
+        retry_after: Option<Duration>,
+        /// Response body, truncated
+        body: String,
+    },
+    /// The API failed to process the request (5xx)
+    ServerError {
+        /// HTTP status code
+        status: u16,
+        /// Response body, truncated
+        body: String,
+    },
+    /// The API returned any other non-success status
+    UnexpectedStatus {
+        /// HTTP status code
+        status: u16,
+        /// Response body, truncated
+        body: String,
+    },
+    /// The client-side daily request budget is used up
+    BudgetExhausted {
+        /// The configured number of requests per day
+        budget: u32,
+    },
+    /// A cassette could not be read or written, or has no matching interaction
+    Cassette(String),
+    /// Archived media could not be written
+    Media(String),
+}
+
+impl HomegateError {
+    /// Creates the error matching a non-success HTTP status.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - HTTP status code of the response
+    /// * `retry_after` - Delay parsed from the `Retry-After` header, if any
+    /// * `body` - Response body (truncated to 512 characters)
+    pub fn from_status(status: StatusCode, retry_after: Option<Duration>, body: &str) -> Self {
+        let body = truncate_body(body);
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => HomegateError::Unauthorized {
+                status: status.as_u16(),
+                body,
+            },
+            StatusCode::TOO_MANY_REQUESTS => HomegateError::RateLimited { retry_after, body },
+            s if s.is_server_error() => HomegateError::ServerError {
+                status: status.as_u16(),
+                body,
+            },
+            _ => HomegateError::UnexpectedStatus {
+                status: status.as_u16(),
+                body,
+            },
+        }
+    }
+
+    /// Returns `true` if the request may succeed when retried later.
+    ///
+    /// This is the case for rate limiting, server errors, timeouts and connection
+    /// failures. Use [`HomegateError::retry_after`] to find out how long to wait.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HomegateError::RateLimited { .. } | HomegateError::ServerError { .. } => true,
+            HomegateError::Request(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Returns the delay requested by the API before retrying, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            HomegateError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code for status errors.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            HomegateError::Unauthorized { status, .. }
+            | HomegateError::ServerError { status, .. }
+            | HomegateError::UnexpectedStatus { status, .. } => Some(*status),
+            HomegateError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS.as_u16()),
+            HomegateError::Request(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+}
+
+/// Truncates a response body to [`MAX_ERROR_BODY_CHARS`] characters.
+fn truncate_body(body: &str) -> String {
+    match body.char_indices().nth(MAX_ERROR_BODY_CHARS) {
+        Some((idx, _)) => format!("{}…", &body[..idx]),
+        None => body.to_string(),
+    }
 }
 
 impl fmt::Display for HomegateError {
@@ -29,6 +143,33 @@ impl fmt::Display for HomegateError {
             HomegateError::InvalidHeader(s) => write!(f, "Invalid header value: {}", s),
             HomegateError::InvalidUrl(e) => write!(f, "Invalid URL: {}", e),
             HomegateError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            HomegateError::Unauthorized { status, body } => {
+                write!(f, "Unauthorized ({}): {}", status, body)
+            }
+            HomegateError::RateLimited {
+                retry_after: Some(retry_after),
+                body,
+            } => write!(
+                f,
+                "Rate limited (retry after {}s): {}",
+                retry_after.as_secs(),
+                body
+            ),
+            HomegateError::RateLimited {
+                retry_after: None,
+                body,
+            } => write!(f, "Rate limited: {}", body),
+            HomegateError::ServerError { status, body } => {
+                write!(f, "Server error ({}): {}", status, body)
+            }
+            HomegateError::UnexpectedStatus { status, body } => {
+                write!(f, "Unexpected status ({}): {}", status, body)
+            }
+            HomegateError::BudgetExhausted { budget } => {
+                write!(f, "Daily request budget of {} exhausted", budget)
+            }
+            HomegateError::Cassette(msg) => write!(f, "Cassette error: {}", msg),
+            HomegateError::Media(msg) => write!(f, "Media archive error: {}", msg),
         }
     }
 }
@@ -55,18 +196,44 @@ impl From<url::ParseError> for HomegateError {
 
 impl From<reqwest_middleware::Error> for HomegateError {
     fn from(err: reqwest_middleware::Error) -> Self {
-        // reqwest_middleware::Error can wrap different error types
-        // For now, we'll treat all middleware errors as generic middleware errors
-        // This includes retry exhaustion, timeout, etc.
-        if err.is_request() {
-            // If there's an underlying reqwest error, we can't easily extract it
-            // without consuming the error, so we convert to string
-            HomegateError::Middleware(format!("Request error: {}", err))
-        } else {
-            HomegateError::Middleware(err.to_string())
+        if let Some(budget) = exhausted_budget(&err) {
+            return HomegateError::BudgetExhausted { budget };
+        }
+        let inner = match err {
+            reqwest_middleware::Error::Reqwest(e) => return HomegateError::Request(e),
+            reqwest_middleware::Error::Middleware(inner) => inner,
+        };
+        // The retry middleware wraps the last error once it gives up, so that
+        // timeouts and connection failures still surface as `Request` errors
+        let inner = match inner.downcast::<RetryError>() {
+            Ok(RetryError::Error(err) | RetryError::WithRetries { err, .. }) => {
+                return HomegateError::from(err)
+            }
+            Err(inner) => inner,
+        };
+        // Errors raised by this crate's own layers, e.g. the cassette
+        match inner.downcast::<HomegateError>() {
+            Ok(err) => err,
+            Err(inner) => HomegateError::Middleware(inner.to_string()),
         }
     }
 }
 
+/// Finds a daily budget error from the rate limiter inside a middleware error.
+///
+/// The retry middleware wraps errors raised by the layers after it in a
+/// [`RetryError`], which is unwrapped here.
+fn exhausted_budget(err: &reqwest_middleware::Error) -> Option<u32> {
+    let reqwest_middleware::Error::Middleware(inner) = err else {
+        return None;
+    };
+    if let Some(RateLimitError::BudgetExhausted { budget }) = inner.downcast_ref() {
+        return Some(*budget);
+    }
+    match inner.downcast_ref::<RetryError>()? {
+        RetryError::Error(err) | RetryError::WithRetries { err, .. } => exhausted_budget(err),
+    }
+}
+
 /// Type alias for Result with HomegateError as the error type
 pub type Result<T> = std::result::Result<T, HomegateError>;
@@ -48,8 +48,8 @@
 //!     let listing = real_estate.listing;
 //!     println!("Property: {}", listing.id);
 //!     println!("  Address: {:?}", listing.address.locality);
-//!     println!("  Rooms: {}", listing.characteristics.number_of_rooms);
-//!     println!("  Size: {}m²", listing.characteristics.living_space);
+//!     println!("  Rooms: {:?}", listing.characteristics.number_of_rooms);
+//!     println!("  Size: {:?}m²", listing.characteristics.living_space);
 //! }
 //! # Ok(())
 //! # }
@@ -67,11 +67,16 @@
 //!
 //! - [`api`] - API client functionality and search operations
 //!   - [`api::search`] - Search for real estate listings
+//!   - [`api::media`] - Download and archive listing media
 //! - [`models`] - Data structures for API responses
 //!   - [`models::listing`] - Listing details and property categories
+//!   - [`models::listing_detail`] - Full details of a single listing
 //!   - [`models::realestate`] - Real estate containers and offer types
 //!   - [`models::address`] - Address and location data
 //!   - [`models::paginated`] - Paginated response handling
+//!   - [`models::money`] - Monetary amounts and normalized prices
+//! - [`clock`] - Injectable time source for time-based logic
+//! - [`testing`] - Offline mock of the Homegate API for tests (requires the `mock` feature)
 //!
 //! ## Advanced Example
 //!
@@ -88,7 +93,8 @@
 //!     latitude: 47.36667,
 //!     longitude: 8.55,
 //!     radius: 2000,
-//! };
+//! }
+//! .into();
 //! search_req.query.monthly_rent = FromTo {
 //!     from: Some(1000),
 //!     to: Some(2500),
@@ -121,9 +127,12 @@
 //! Use this library responsibly and in compliance with Homegate's terms of service.
 
 pub mod api;
+pub mod clock;
 pub mod config;
 pub mod error;
 pub mod models;
+#[cfg(feature = "mock")]
+pub mod testing;
 
 // Re-export commonly used types for convenience
 pub use api::request::HomegateClient;
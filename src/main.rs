@@ -1,55 +1,89 @@
+use std::path::PathBuf;
 use std::process;
 
 use clap::{Parser, Subcommand};
 use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
 use console::{style, Emoji};
-use url::Url;
-
+use homegate::api::media::MediaArchiver;
 use homegate::api::request::HomegateClient;
-use homegate::api::search::{default_search, Location};
-use homegate::api::BACKEND_URL;
-use homegate::models::paginated::parse_search_result;
-use homegate::models::realestate::OfferType;
-
+use homegate::api::search::{Location, SearchLocation, SearchQuery, SortBy, SortDirection};
+use homegate::config::{CacheConfig, HomegateConfig, ParseMode};
+use homegate::models::listing::{Category, Lang};
+use homegate::models::realestate::{group_by_lister, OfferType};
+#[cfg(feature = "mock")]
+use homegate::testing::MockHomegate;
+
+#[cfg(feature = "mcp")]
 mod mcp;
 
 /// Homegate.ch CLI and MCP server for real estate search
 #[derive(Parser, Debug)]
 #[command(name = "homegate")]
 #[command(author, version, about, long_about = None)]
+#[cfg_attr(
+    not(feature = "mock"),
+    command(after_help = "The mock-server subcommand requires building with `--features mock`.")
+)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Do not read or write the response cache
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Ignore cached responses but store the fresh ones
+    #[arg(long, global = true, conflicts_with = "no_cache")]
+    refresh: bool,
+
+    /// Preferred languages for listing texts, in order (de, en, fr, it; comma-separated).
+    /// Falls back to each listing's primary language
+    #[arg(long, global = true, value_delimiter = ',')]
+    lang: Vec<Lang>,
 }
 
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Search for real estate listings (default if no subcommand)
     Search(SearchArgs),
+    /// Resolve free text (city, postal code, district) into locations
+    Locations(LocationsArgs),
+    /// Show the full details of a listing
+    Show(ShowArgs),
+    /// Download and archive the media of a listing, with a manifest
+    Media(MediaArgs),
     /// Run as MCP (Model Context Protocol) server
+    #[cfg(feature = "mcp")]
     Serve,
+    /// Serve a local mock of the Homegate API from fixture files
+    #[cfg(feature = "mock")]
+    MockServer(MockServerArgs),
 }
 
 /// Arguments for the search command
 #[derive(Parser, Debug)]
 struct SearchArgs {
+    /// Location to search in, as free text (e.g. "Winterthur", "8004")
+    #[arg(long, conflicts_with_all = ["lat", "lon"])]
+    location: Option<String>,
+
     /// Latitude (-90 to 90)
-    #[arg(long)]
-    lat: f32,
+    #[arg(long, required_unless_present = "location", requires = "lon")]
+    lat: Option<f32>,
 
     /// Longitude (-180 to 180)
-    #[arg(long)]
-    lon: f32,
+    #[arg(long, required_unless_present = "location", requires = "lat")]
+    lon: Option<f32>,
 
-    /// Search radius in meters (default: 5000, max: 49999)
-    #[arg(long, default_value_t = 5000)]
+    /// Search radius in meters around --lat/--lon (default: 5000, max: 49999)
+    #[arg(long, default_value_t = 5000, conflicts_with = "location")]
     radius: u32,
 
-    /// Minimum monthly rent/price in CHF
+    /// Minimum price in CHF (monthly rent, or purchase price with --offer-type buy)
     #[arg(long)]
     min_price: Option<u32>,
 
-    /// Maximum monthly rent/price in CHF
+    /// Maximum price in CHF (monthly rent, or purchase price with --offer-type buy)
     #[arg(long)]
     max_price: Option<u32>,
 
@@ -78,37 +112,118 @@ struct SearchArgs {
     #[arg(long, value_delimiter = ',')]
     exclude_category: Option<Vec<String>>,
 
-    /// Offer type: rent (default)
+    /// Offer type: rent (default) or buy
     #[arg(long, default_value = "rent")]
     offer_type: String,
 
+    /// Sort order: relevance (default), price, living-space, rooms, newest, distance
+    #[arg(long, default_value = "relevance")]
+    sort: String,
+
+    /// Sort direction: asc or desc (default depends on --sort, e.g. desc for newest)
+    #[arg(long)]
+    sort_direction: Option<String>,
+
     /// Page number (1-indexed)
     #[arg(long, default_value_t = 1)]
     page: u32,
 
     /// Results per page
     #[arg(long, default_value_t = 20)]
-    page_size: i32,
+    page_size: u32,
+
+    /// Group the results by lister (agency or property manager)
+    #[arg(long)]
+    group_by_lister: bool,
+
+    /// Output as JSON instead of table
+    #[arg(long)]
+    json: bool,
+}
+
+/// Arguments for the locations command
+#[derive(Parser, Debug)]
+struct LocationsArgs {
+    /// Free text to resolve (e.g. "Winterthur", "8004", "Kreis 4")
+    text: String,
 
     /// Output as JSON instead of table
     #[arg(long)]
     json: bool,
 }
 
+/// Arguments for the show command
+#[derive(Parser, Debug)]
+struct ShowArgs {
+    /// Listing id
+    id: String,
+
+    /// Output as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+/// Arguments for the media command
+#[derive(Parser, Debug)]
+struct MediaArgs {
+    /// Listing id
+    id: String,
+
+    /// Directory to archive into (default: ./<id>)
+    #[arg(long)]
+    out: Option<PathBuf>,
+
+    /// Output the manifest as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+/// Arguments for the mock-server command
+#[cfg(feature = "mock")]
+#[derive(Parser, Debug)]
+struct MockServerArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: String,
+
+    /// Search response fixtures to serve (defaults to every result-*.json in --fixture-dir)
+    #[arg(long = "fixture", value_name = "FILE")]
+    fixtures: Vec<PathBuf>,
+
+    /// Directory searched for result-*.json fixtures
+    #[arg(long, default_value = "resources/test")]
+    fixture_dir: PathBuf,
+}
+
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    let config = client_config(&cli);
 
     let result = match cli.command {
-        Some(Commands::Search(args)) => run_search(args).await,
-        Some(Commands::Serve) => run_mcp_server().await,
+        Some(Commands::Search(args)) => run_search(args, &config, &cli.lang).await,
+        Some(Commands::Locations(args)) => run_locations(args, &config).await,
+        Some(Commands::Show(args)) => run_show(args, &config, &cli.lang).await,
+        Some(Commands::Media(args)) => run_media(args, &config).await,
+        #[cfg(feature = "mcp")]
+        Some(Commands::Serve) => run_mcp_server(config).await,
+        #[cfg(feature = "mock")]
+        Some(Commands::MockServer(args)) => run_mock_server(args).await,
         None => {
             // If no subcommand, show help
             eprintln!("Usage: homegate <COMMAND>");
             eprintln!();
             eprintln!("Commands:");
-            eprintln!("  search  Search for real estate listings");
-            eprintln!("  serve   Run as MCP server");
+            eprintln!("  search       Search for real estate listings");
+            eprintln!("  locations    Resolve free text into locations");
+            eprintln!("  show         Show the full details of a listing");
+            eprintln!("  media        Download and archive the media of a listing");
+            #[cfg(feature = "mcp")]
+            eprintln!("  serve        Run as MCP server");
+            #[cfg(feature = "mock")]
+            eprintln!("  mock-server  Serve a local mock of the Homegate API");
+            #[cfg(not(feature = "mock"))]
+            eprintln!("  mock-server  Not available, requires building with --features mock");
             eprintln!();
             eprintln!("Run 'homegate --help' for more information");
             process::exit(1);
@@ -121,127 +236,398 @@ async fn main() {
     }
 }
 
-async fn run_mcp_server() -> Result<(), Box<dyn std::error::Error>> {
+/// Builds the client configuration from the global command line flags.
+///
+/// The CLI caches responses unless `--no-cache` is given, and skips malformed
+/// listings instead of failing the whole page.
+fn client_config(cli: &Cli) -> HomegateConfig {
+    let cache = (!cli.no_cache).then(|| CacheConfig {
+        refresh: cli.refresh,
+        ..CacheConfig::default()
+    });
+    HomegateConfig {
+        cache,
+        parse_mode: ParseMode::Lenient,
+        ..HomegateConfig::default()
+    }
+}
+
+#[cfg(feature = "mcp")]
+async fn run_mcp_server(config: HomegateConfig) -> Result<(), Box<dyn std::error::Error>> {
     use rmcp::transport::stdio;
     use rmcp::ServiceExt;
 
-    let server = mcp::HomegateServer::new();
+    let server = mcp::HomegateServer::with_config(config)?;
     let transport = stdio();
     server.serve(transport).await?.waiting().await?;
     Ok(())
 }
 
-async fn run_search(args: SearchArgs) -> Result<(), Box<dyn std::error::Error>> {
-    // Validate location
-    let location = Location {
-        latitude: args.lat,
-        longitude: args.lon,
-        radius: args.radius,
+#[cfg(feature = "mock")]
+async fn run_mock_server(args: MockServerArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mock = if args.fixtures.is_empty() {
+        MockHomegate::from_dir(&args.fixture_dir)?
+    } else {
+        MockHomegate::from_fixtures(&args.fixtures)?
     };
-    location
-        .validate()
-        .map_err(|e| format!("Invalid location: {}", e))?;
 
-    // Build search request from defaults
-    let mut search_request = default_search();
+    let listener = tokio::net::TcpListener::bind(&args.addr).await?;
+    eprintln!(
+        "Serving {} listings on http://{}",
+        mock.len(),
+        listener.local_addr()?
+    );
+    mock.serve(listener).await?;
+    Ok(())
+}
 
-    // Override location
-    search_request.query.location = location;
+async fn run_search(
+    args: SearchArgs,
+    config: &HomegateConfig,
+    lang: &[Lang],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sort_by = args.sort.parse::<SortBy>()?;
+    let sort_direction = match &args.sort_direction {
+        Some(direction) => direction.parse::<SortDirection>()?,
+        None => sort_by.default_direction(),
+    };
 
-    // Override price filter if specified
-    if args.min_price.is_some() || args.max_price.is_some() {
-        if args.min_price.is_some() {
-            search_request.query.monthly_rent.from = args.min_price;
-        }
-        if args.max_price.is_some() {
-            search_request.query.monthly_rent.to = args.max_price;
+    let client = HomegateClient::from_config(config)?;
+
+    // Free text is resolved only after the rest of the query is validated, so
+    // invalid arguments do not spend a request on the lookup
+    let location: SearchLocation = match (&args.location, args.lat, args.lon) {
+        (Some(text), _, _) => SearchLocation::GeoTags {
+            geo_tags: vec![text.clone()],
+        },
+        (None, Some(latitude), Some(longitude)) => Location {
+            latitude,
+            longitude,
+            radius: args.radius,
         }
+        .into(),
+        _ => return Err("Either --location or both --lat and --lon are required".into()),
+    };
+
+    let offer_type = args.offer_type.parse::<OfferType>()?;
+    let mut query = SearchQuery::new(location)
+        .offer_type(offer_type.clone())
+        .price(args.min_price, args.max_price)
+        .number_of_rooms(args.min_rooms, args.max_rooms)
+        .living_space(args.min_space, args.max_space)
+        .sort(sort_by, sort_direction)
+        .page(args.page)
+        .page_size(args.page_size);
+
+    if let Some(categories) = &args.category {
+        query = query.categories(parse_categories(categories)?);
     }
-    search_request
-        .query
-        .monthly_rent
-        .validate()
-        .map_err(|e| format!("Invalid price range: {}", e))?;
-
-    // Override rooms filter if specified
-    if args.min_rooms.is_some() || args.max_rooms.is_some() {
-        if args.min_rooms.is_some() {
-            search_request.query.number_of_rooms.from = args.min_rooms;
-        }
-        if args.max_rooms.is_some() {
-            search_request.query.number_of_rooms.to = args.max_rooms;
-        }
+    if let Some(exclude) = &args.exclude_category {
+        query = query.exclude_categories(parse_categories(exclude)?);
     }
-    search_request
-        .query
-        .number_of_rooms
-        .validate()
-        .map_err(|e| format!("Invalid rooms range: {}", e))?;
-
-    // Override living space filter if specified
-    if args.min_space.is_some() || args.max_space.is_some() {
-        if args.min_space.is_some() {
-            search_request.query.living_space.from = args.min_space;
-        }
-        if args.max_space.is_some() {
-            search_request.query.living_space.to = args.max_space;
-        }
+    query.validate()?;
+
+    if let Some(text) = &args.location {
+        let suggestions = client.geo_lookup(text).await?;
+        let location = suggestions
+            .first()
+            .ok_or_else(|| format!("No location found for \"{}\"", text))?;
+        query = query.location(location);
     }
-    search_request
-        .query
-        .living_space
-        .validate()
-        .map_err(|e| format!("Invalid space range: {}", e))?;
-
-    // Override categories if specified
-    if let Some(categories) = args.category {
-        search_request.query.categories = categories
-            .into_iter()
-            .map(|c| c.to_uppercase().replace('-', "_"))
-            .collect();
+
+    // Execute search
+    let results = client.search_with(&query).await?;
+
+    // Output results
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else if args.group_by_lister {
+        print_lister_table(&results);
+    } else {
+        print_table(&results, args.page, args.page_size, &offer_type, lang);
+    }
+
+    Ok(())
+}
+
+async fn run_locations(
+    args: LocationsArgs,
+    config: &HomegateConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = HomegateClient::from_config(config)?;
+    let locations = client.geo_lookup(&args.text).await?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&locations)?);
+        return Ok(());
+    }
+
+    if locations.is_empty() {
+        println!("{}", style("No locations found").yellow());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Id")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Coordinates")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for location in &locations {
+        let coordinates = location
+            .geo_coordinates()
+            .map(|c| format!("{:.5}, {:.5}", c.latitude, c.longitude))
+            .unwrap_or_else(|| "-".to_string());
+        table.add_row(vec![
+            Cell::new(location.name()),
+            Cell::new(location.type_label()),
+            Cell::new(location.id()).fg(Color::Blue),
+            Cell::new(&coordinates),
+        ]);
     }
 
-    // Override exclude categories if specified
-    if let Some(exclude) = args.exclude_category {
-        search_request.query.exclude_categories = exclude
-            .into_iter()
-            .map(|c| c.to_uppercase().replace('-', "_"))
-            .collect();
+    println!("{table}");
+    Ok(())
+}
+
+async fn run_show(
+    args: ShowArgs,
+    config: &HomegateConfig,
+    lang: &[Lang],
+) -> Result<(), Box<dyn std::error::Error>> {
+    static HOUSE: Emoji<'_, '_> = Emoji("🏠 ", "");
+
+    let client = HomegateClient::from_config(config)?;
+    let detail = client.get_listing(&args.id).await?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&detail)?);
+        return Ok(());
     }
 
-    // Override offer type
-    search_request.query.offer_type = match args.offer_type.to_lowercase().as_str() {
-        "rent" => OfferType::RENT,
-        other => return Err(format!("Unknown offer type: {}. Supported: rent", other).into()),
+    let text = detail.localization.resolve(lang).map(|e| &e.text);
+
+    println!();
+    println!(
+        "{} {}",
+        HOUSE,
+        style(text.map(|t| t.title.as_str()).unwrap_or("-"))
+            .bold()
+            .cyan()
+    );
+    println!("{}", style("─".repeat(50)).dim());
+    println!();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    let address = format!(
+        "{}, {} {}",
+        detail.address.street.as_deref().unwrap_or("-"),
+        detail.address.postal_code,
+        detail.address.locality.as_deref().unwrap_or("")
+    );
+    let price = detail
+        .prices
+        .for_offer_type(&detail.offer_type)
+        .and_then(|p| p.amount())
+        .map(|a| format!("{} CHF", a))
+        .unwrap_or_else(|| "-".to_string());
+    let categories = detail
+        .categories
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut rows = vec![
+        ("Id", detail.id.clone()),
+        ("Address", address),
+        ("Categories", categories),
+        ("Price", price),
+        (
+            "Available from",
+            detail
+                .available_from
+                .map(|date| date.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        ("Features", detail.features.join(", ")),
+        ("Attachments", detail.attachments().len().to_string()),
+        (
+            "Phone",
+            detail
+                .lister
+                .as_ref()
+                .and_then(|l| l.phone.clone())
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+    let characteristics = match serde_json::to_value(&detail.characteristics) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
     };
+    for (key, value) in &characteristics {
+        rows.push((key.as_str(), value.to_string()));
+    }
 
-    // Pagination
-    search_request.size = args.page_size;
-    search_request.from = ((args.page - 1) as i32) * args.page_size;
+    for (key, value) in rows {
+        table.add_row(vec![
+            Cell::new(key).add_attribute(Attribute::Bold),
+            Cell::new(&value),
+        ]);
+    }
+    println!("{table}");
 
-    // Execute search
-    let client = HomegateClient::new()?;
-    let url = Url::parse(&format!("{}/search/listings", BACKEND_URL))?;
-    let body = serde_json::to_string(&search_request)?;
-    let resp = client.post_url(url, &body).await?;
-    let text = resp.text().await?;
-    let results = parse_search_result(&text)?;
+    if let Some(description) = text.and_then(|t| t.description.as_deref()) {
+        println!();
+        println!("{}", description);
+    }
+    println!();
+
+    Ok(())
+}
+
+async fn run_media(
+    args: MediaArgs,
+    config: &HomegateConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = HomegateClient::from_config(config)?;
+    let detail = client.get_listing(&args.id).await?;
+
+    let out = args.out.unwrap_or_else(|| PathBuf::from(&detail.id));
+    let manifest = MediaArchiver::from_config(config)?
+        .archive(&detail, &out)
+        .await?;
 
-    // Output results
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&results)?);
-    } else {
-        print_table(&results, args.page, args.page_size, &args.offer_type);
+        println!("{}", serde_json::to_string_pretty(&manifest)?);
+        return Ok(());
     }
 
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Kind")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("File")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Size")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Title")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for item in &manifest.items {
+        let file = match (&item.file, &item.error) {
+            (Some(file), _) => Cell::new(file),
+            (None, Some(error)) => Cell::new(error).fg(Color::Red),
+            (None, None) => Cell::new(&item.url).fg(Color::Blue),
+        };
+        table.add_row(vec![
+            Cell::new(&item.kind),
+            file,
+            Cell::new(
+                item.size
+                    .map(|s| format!("{} KB", s.div_ceil(1024)))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(item.title.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{table}");
+    println!();
+    println!(
+        "Archived {} attachments to {} ({} failed)",
+        style(manifest.items.len()).bold(),
+        style(out.display()).cyan(),
+        manifest.failed().count()
+    );
+
     Ok(())
 }
 
+/// Parses category names given on the command line (e.g. `attic-flat`).
+fn parse_categories(categories: &[String]) -> Result<Vec<Category>, String> {
+    categories.iter().map(|c| c.parse()).collect()
+}
+
+fn print_lister_table(results: &homegate::Paginated<homegate::RealEstate>) {
+    if results.results.is_empty() {
+        println!("{}", style("No results found").yellow());
+        return;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Lister")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Phone")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Listings")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("IDs")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for group in group_by_lister(&results.results) {
+        let name = match &group.key {
+            Some(_) => group.lister.name.as_deref().unwrap_or("-"),
+            None => "(no lister information)",
+        };
+        let ids = group
+            .listings
+            .iter()
+            .map(|item| item.listing.id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        table.add_row(vec![
+            Cell::new(name),
+            Cell::new(group.lister.phone.as_deref().unwrap_or("-")),
+            Cell::new(group.listings.len()).fg(Color::Green),
+            Cell::new(&ids),
+        ]);
+    }
+
+    println!("{table}");
+}
+
 fn print_table(
     results: &homegate::Paginated<homegate::RealEstate>,
     page: u32,
-    page_size: i32,
-    offer_type: &str,
+    page_size: u32,
+    offer_type: &OfferType,
+    lang: &[Lang],
 ) {
     static HOUSE: Emoji<'_, '_> = Emoji("🏠 ", "");
     static LINK: Emoji<'_, '_> = Emoji("🔗 ", "");
@@ -281,6 +667,9 @@ fn print_table(
             Cell::new("Price (CHF)")
                 .add_attribute(Attribute::Bold)
                 .fg(Color::Cyan),
+            Cell::new("CHF/m²")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
             Cell::new("Link")
                 .add_attribute(Attribute::Bold)
                 .fg(Color::Cyan),
@@ -288,19 +677,17 @@ fn print_table(
 
     // Determine URL base based on offer type
     let url_base = match offer_type {
-        "rent" => "https://www.homegate.ch/rent",
-        "buy" => "https://www.homegate.ch/buy",
-        _ => "https://www.homegate.ch/rent",
+        OfferType::BUY => "https://www.homegate.ch/buy",
+        OfferType::RENT | OfferType::Unknown(_) => "https://www.homegate.ch/rent",
     };
 
     for item in &results.results {
         let listing = &item.listing;
 
-        // Get title from localization (try German first as it's the most common)
+        // Get title in the preferred language, falling back to the listing's own
         let title_full = listing
             .localization
-            .de
-            .as_ref()
+            .resolve(lang)
             .map(|loc| loc.text.title.as_str())
             .unwrap_or("-");
 
@@ -320,30 +707,46 @@ fn print_table(
         );
 
         // Format rooms
-        let rooms = format!("{:.1}", listing.characteristics.number_of_rooms);
+        let rooms = listing
+            .characteristics
+            .number_of_rooms
+            .map(|r| format!("{:.1}", r))
+            .unwrap_or_else(|| "-".to_string());
 
         // Format space
-        let space = format!("{} m²", listing.characteristics.living_space);
-
-        // Format price
-        let price = if let Some(ref rent) = listing.prices.rent {
-            if let Some(gross) = rent.gross {
-                format!("{}/mo", gross)
-            } else if let Some(net) = rent.net {
-                format!("{}/mo (net)", net)
-            } else {
-                "-".to_string()
-            }
-        } else if let Some(ref buy) = listing.prices.buy {
-            if let Some(gross) = buy.gross {
-                format!("{}", gross)
-            } else {
-                "-".to_string()
-            }
-        } else {
-            "-".to_string()
+        let space = listing
+            .characteristics
+            .living_space
+            .map(|s| format!("{} m²", s))
+            .unwrap_or_else(|| "-".to_string());
+
+        // Format price, converting weekly, yearly and per m² rents to monthly ones
+        let price = match listing.prices.for_offer_type(&listing.offer_type) {
+            Some(p) => match &listing.offer_type {
+                OfferType::RENT => listing
+                    .normalized_monthly_gross()
+                    .map(|m| format!("{}/mo", m.rounded()))
+                    .or_else(|| {
+                        listing
+                            .normalized_monthly_net()
+                            .map(|m| format!("{}/mo (net)", m.rounded()))
+                    })
+                    .unwrap_or_else(|| "-".to_string()),
+                OfferType::BUY => p
+                    .amount()
+                    .map(|a| a.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                _ => "-".to_string(),
+            },
+            None => "-".to_string(),
         };
 
+        // Format price per m²
+        let price_per_m2 = listing
+            .price_per_m2()
+            .map(|m| m.rounded().to_string())
+            .unwrap_or_else(|| "-".to_string());
+
         // Build listing URL
         let url = format!("{}/{}", url_base, listing.id);
 
@@ -353,6 +756,7 @@ fn print_table(
             Cell::new(&rooms),
             Cell::new(&space),
             Cell::new(&price).fg(Color::Green),
+            Cell::new(&price_per_m2),
             Cell::new(&url).fg(Color::Blue),
         ]);
     }
@@ -362,7 +766,7 @@ fn print_table(
 
     // Styled pagination info
     let total_pages = (results.total as f64 / page_size as f64).ceil() as u32;
-    let start = ((page - 1) * page_size as u32) + 1;
+    let start = ((page - 1) * page_size) + 1;
     let end = std::cmp::min(start + results.results.len() as u32 - 1, results.total);
 
     println!(
@@ -1,8 +1,14 @@
 //! MCP (Model Context Protocol) server implementation for Homegate.
 //!
-//! This module provides an MCP server that exposes the Homegate search
-//! functionality as a tool that can be called by AI assistants.
+//! This module provides an MCP server that exposes the Homegate search and
+//! listing detail functionality as tools that can be called by AI assistants.
 
+use homegate::api::request::HomegateClient;
+use homegate::api::search::{Location, SearchLocation, SearchQuery, SortBy, SortDirection};
+use homegate::config::HomegateConfig;
+use homegate::models::listing::{Category, Lang};
+use homegate::models::realestate::OfferType;
+use homegate::HomegateError;
 use rmcp::{
     handler::server::tool::ToolRouter,
     handler::server::wrapper::Parameters,
@@ -11,12 +17,6 @@ use rmcp::{
     tool, tool_router, ErrorData as McpError,
 };
 use serde::{Deserialize, Serialize};
-use url::Url;
-
-use homegate::api::request::HomegateClient;
-use homegate::api::search::{default_search, Location};
-use homegate::api::BACKEND_URL;
-use homegate::models::paginated::parse_search_result;
 
 /// Default search radius in meters
 fn default_radius() -> u32 {
@@ -29,23 +29,28 @@ fn default_page() -> u32 {
 }
 
 /// Default page size
-fn default_page_size() -> i32 {
+fn default_page_size() -> u32 {
     20
 }
 
 /// Parameters for the search tool
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct SearchParams {
+    /// Location as free text, e.g. "Winterthur", "8004" or "Kreis 4" (alternative to latitude/longitude)
+    pub location: Option<String>,
     /// Latitude in degrees (-90 to 90)
-    pub latitude: f32,
+    pub latitude: Option<f32>,
     /// Longitude in degrees (-180 to 180)
-    pub longitude: f32,
-    /// Search radius in meters (default: 5000, max: 49999)
+    pub longitude: Option<f32>,
+    /// Search radius in meters around latitude/longitude (default: 5000, max: 49999).
+    /// Ignored when searching by location text.
     #[serde(default = "default_radius")]
     pub radius: u32,
-    /// Minimum monthly rent/price in CHF
+    /// Offer type: "rent" (default) or "buy"
+    pub offer_type: Option<String>,
+    /// Minimum price in CHF (monthly rent, or purchase price when buying)
     pub min_price: Option<u32>,
-    /// Maximum monthly rent/price in CHF
+    /// Maximum price in CHF (monthly rent, or purchase price when buying)
     pub max_price: Option<u32>,
     /// Minimum number of rooms (supports fractional values like 2.5, 3.5)
     pub min_rooms: Option<f32>,
@@ -57,12 +62,26 @@ pub struct SearchParams {
     pub max_space: Option<u32>,
     /// Property categories to include (e.g., APARTMENT, STUDIO, VILLA)
     pub categories: Option<Vec<String>>,
+    /// Sort order: relevance (default), price, living-space, rooms, newest, distance
+    pub sort: Option<String>,
+    /// Sort direction: asc or desc (default depends on sort, e.g. desc for newest)
+    pub sort_direction: Option<String>,
     /// Page number (1-indexed, default: 1)
     #[serde(default = "default_page")]
     pub page: u32,
     /// Results per page (default: 20)
     #[serde(default = "default_page_size")]
-    pub page_size: i32,
+    pub page_size: u32,
+    /// Preferred languages for titles, comma-separated (de, en, fr, it), e.g. "fr,en".
+    /// Falls back to each listing's primary language
+    pub lang: Option<String>,
+}
+
+/// Parameters for the get_listing tool
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetListingParams {
+    /// Listing id, as returned by the search tool
+    pub id: String,
 }
 
 /// Simplified listing for MCP response
@@ -70,6 +89,8 @@ pub struct SearchParams {
 pub struct ListingResult {
     /// Listing ID
     pub id: String,
+    /// Title in the preferred language, or the listing's primary language
+    pub title: Option<String>,
     /// Street address
     pub street: Option<String>,
     /// Postal code
@@ -77,13 +98,23 @@ pub struct ListingResult {
     /// City/locality
     pub locality: Option<String>,
     /// Number of rooms
-    pub rooms: f32,
+    pub rooms: Option<f32>,
     /// Living space in square meters
-    pub living_space: u32,
-    /// Monthly rent (gross) in CHF
+    pub living_space: Option<u32>,
+    /// Offer type (RENT or BUY)
+    pub offer_type: String,
+    /// Gross price in CHF (monthly rent, or purchase price when buying)
     pub price_gross: Option<u32>,
-    /// Monthly rent (net) in CHF
+    /// Net price in CHF (monthly rent, or purchase price when buying)
     pub price_net: Option<u32>,
+    /// Gross rent per month in CHF, converted from weekly, yearly or per m² prices
+    pub monthly_gross: Option<f64>,
+    /// Price per m² in CHF (monthly gross rent, or purchase price when buying)
+    pub price_per_m2: Option<f64>,
+    /// Placement tier: PREMIUM and TOP are paid placements, STANDARD is organic
+    pub listing_type: Option<String>,
+    /// Whether the listing offers a remote viewing (virtual tour)
+    pub remote_viewing: bool,
 }
 
 /// Search results response
@@ -104,109 +135,143 @@ pub struct SearchResult {
 pub struct HomegateServer {
     #[allow(dead_code)]
     tool_router: ToolRouter<Self>,
+    /// Client shared by every tool call, so that its rate limit and daily budget
+    /// apply across requests
+    client: HomegateClient,
 }
 
 #[tool_router]
 impl HomegateServer {
-    /// Create a new Homegate MCP server
-    pub fn new() -> Self {
-        Self {
+    /// Create a new Homegate MCP server whose client uses the given configuration
+    pub fn with_config(config: HomegateConfig) -> homegate::Result<Self> {
+        Ok(Self {
             tool_router: Self::tool_router(),
-        }
+            client: HomegateClient::from_config(&config)?,
+        })
     }
 
     /// Search for real estate listings on Homegate.ch
     #[tool(
-        description = "Search for real estate listings (apartments, houses, etc.) on Homegate.ch in Switzerland. Returns property details including address, rooms, size, and price."
+        description = "Search for real estate listings (apartments, houses, etc.) for rent or sale on Homegate.ch in Switzerland. Returns property details including address, rooms, size, and price."
     )]
     async fn search(
         &self,
         Parameters(params): Parameters<SearchParams>,
     ) -> Result<CallToolResult, McpError> {
-        // Validate location
-        let location = Location {
-            latitude: params.latitude,
-            longitude: params.longitude,
-            radius: params.radius,
+        let lang = match params.lang.as_deref() {
+            Some(lang) => lang
+                .split(',')
+                .map(str::parse::<Lang>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| McpError::invalid_params(e, None))?,
+            None => Vec::new(),
         };
 
-        if let Err(e) = location.validate() {
-            return Err(McpError::invalid_params(e, None));
-        }
+        let offer_type = params
+            .offer_type
+            .as_deref()
+            .unwrap_or("rent")
+            .parse::<OfferType>()
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
-        // Build search request
-        let mut search_request = default_search();
-        search_request.query.location = location;
+        let sort_by = params
+            .sort
+            .as_deref()
+            .unwrap_or("relevance")
+            .parse::<SortBy>()
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let sort_direction = match params.sort_direction.as_deref() {
+            Some(direction) => direction
+                .parse::<SortDirection>()
+                .map_err(|e| McpError::invalid_params(e, None))?,
+            None => sort_by.default_direction(),
+        };
 
-        // Override filters if provided
-        if let Some(min) = params.min_price {
-            search_request.query.monthly_rent.from = Some(min);
-        }
-        if let Some(max) = params.max_price {
-            search_request.query.monthly_rent.to = Some(max);
-        }
-        if let Some(min) = params.min_rooms {
-            search_request.query.number_of_rooms.from = Some(min);
-        }
-        if let Some(max) = params.max_rooms {
-            search_request.query.number_of_rooms.to = Some(max);
-        }
-        if let Some(min) = params.min_space {
-            search_request.query.living_space.from = Some(min);
-        }
-        if let Some(max) = params.max_space {
-            search_request.query.living_space.to = Some(max);
-        }
-        if let Some(categories) = params.categories {
-            search_request.query.categories = categories
-                .into_iter()
-                .map(|c| c.to_uppercase().replace('-', "_"))
-                .collect();
-        }
+        let client = &self.client;
 
-        // Pagination
-        search_request.size = params.page_size;
-        search_request.from = ((params.page - 1) as i32) * params.page_size;
+        // Free text is resolved only after the rest of the query is validated, so
+        // invalid parameters do not spend a request on the lookup
+        let location: SearchLocation = match (&params.location, params.latitude, params.longitude) {
+            (Some(text), _, _) => SearchLocation::GeoTags {
+                geo_tags: vec![text.clone()],
+            },
+            (None, Some(latitude), Some(longitude)) => Location {
+                latitude,
+                longitude,
+                radius: params.radius,
+            }
+            .into(),
+            _ => {
+                return Err(McpError::invalid_params(
+                    "Either location or both latitude and longitude are required",
+                    None,
+                ))
+            }
+        };
 
-        // Execute search
-        let client = HomegateClient::new().map_err(|e| {
-            McpError::internal_error(format!("Failed to create client: {}", e), None)
-        })?;
+        let mut query = SearchQuery::new(location)
+            .offer_type(offer_type)
+            .price(params.min_price, params.max_price)
+            .number_of_rooms(params.min_rooms, params.max_rooms)
+            .living_space(params.min_space, params.max_space)
+            .sort(sort_by, sort_direction)
+            .page(params.page)
+            .page_size(params.page_size);
 
-        let url = Url::parse(&format!("{}/search/listings", BACKEND_URL))
-            .map_err(|e| McpError::internal_error(format!("Invalid URL: {}", e), None))?;
+        if let Some(categories) = &params.categories {
+            let categories = categories
+                .iter()
+                .map(|c| c.parse::<Category>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| McpError::invalid_params(e, None))?;
+            query = query.categories(categories);
+        }
 
-        let body = serde_json::to_string(&search_request)
-            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+        query
+            .validate()
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
 
-        let resp = client
-            .post_url(url, &body)
+        if let Some(text) = &params.location {
+            let suggestions = client.geo_lookup(text).await.map_err(|e| {
+                McpError::internal_error(format!("Location lookup failed: {}", e), None)
+            })?;
+            let location = suggestions.first().ok_or_else(|| {
+                McpError::invalid_params(format!("No location found for \"{}\"", text), None)
+            })?;
+            query = query.location(location);
+        }
+
+        // Execute search
+        let results = client
+            .search_with(&query)
             .await
             .map_err(|e| McpError::internal_error(format!("Request failed: {}", e), None))?;
 
-        let text = resp.text().await.map_err(|e| {
-            McpError::internal_error(format!("Failed to read response: {}", e), None)
-        })?;
-
-        let results = parse_search_result(&text).map_err(|e| {
-            McpError::internal_error(format!("Failed to parse response: {}", e), None)
-        })?;
-
         // Convert to simplified response
         let listings: Vec<ListingResult> = results
             .results
             .iter()
             .map(|r| {
                 let listing = &r.listing;
+                let price = listing.prices.for_offer_type(&listing.offer_type);
                 ListingResult {
                     id: listing.id.clone(),
+                    title: listing
+                        .localization
+                        .resolve(&lang)
+                        .map(|entry| entry.text.title.clone()),
                     street: listing.address.street.clone(),
                     postal_code: listing.address.postal_code.clone(),
                     locality: listing.address.locality.clone(),
                     rooms: listing.characteristics.number_of_rooms,
                     living_space: listing.characteristics.living_space,
-                    price_gross: listing.prices.rent.as_ref().and_then(|p| p.gross),
-                    price_net: listing.prices.rent.as_ref().and_then(|p| p.net),
+                    offer_type: listing.offer_type.to_string(),
+                    price_gross: price.and_then(|p| p.gross),
+                    price_net: price.and_then(|p| p.net),
+                    monthly_gross: listing.normalized_monthly_gross().map(|m| m.amount()),
+                    price_per_m2: listing.price_per_m2().map(|m| m.amount()),
+                    listing_type: r.listing_type().map(ToString::to_string),
+                    remote_viewing: r.remote_viewing,
                 }
             })
             .collect();
@@ -225,11 +290,26 @@ impl HomegateServer {
 
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
-}
 
-impl Default for HomegateServer {
-    fn default() -> Self {
-        Self::new()
+    /// Fetch the full details of a single listing
+    #[tool(
+        description = "Get the full details of a Homegate.ch listing by id: descriptions in every language, all characteristics, availability date, features, attachments and lister contact."
+    )]
+    async fn get_listing(
+        &self,
+        Parameters(params): Parameters<GetListingParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let client = &self.client;
+
+        let detail = client.get_listing(&params.id).await.map_err(|e| match e {
+            HomegateError::ValidationError(msg) => McpError::invalid_params(msg, None),
+            e => McpError::internal_error(format!("Request failed: {}", e), None),
+        })?;
+
+        let json = serde_json::to_string_pretty(&detail)
+            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 }
 
@@ -6,6 +6,32 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::geo_coords::GeoCoords;
 
+/// Postal address of a person or company, e.g. a lister.
+///
+/// Unlike [`Address`], it has no coordinates and every field is optional.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostalAddress {
+    /// Street name and number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    /// Additional address line (e.g. "Postfach")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub street_addition: Option<String>,
+    /// Postal code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    /// City or locality name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+    /// Region or canton code (e.g. "ZH")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Country code (e.g. "CH")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
 /// Physical address of a property.
 ///
 /// Contains both structured address fields and geographic coordinates.
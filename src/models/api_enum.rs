@@ -0,0 +1,82 @@
+//! Forward-compatible enums for string values returned by the API.
+
+/// Declares an enum mapping string values of the API to variants, with an extra
+/// `Unknown(String)` variant for values added to the API later.
+///
+/// The generated type serializes to and deserializes from the API value. Unknown
+/// values round-trip unchanged and log a warning when deserialized, so API drift
+/// shows up in the logs instead of failing the whole response.
+macro_rules! api_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this version of the crate, kept verbatim
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Every known variant, in declaration order.
+            pub const KNOWN: &'static [$name] = &[$($name::$variant),*];
+
+            /// Returns the value used by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+
+            /// Returns the known variant for an API value, if any.
+            pub fn from_known(value: &str) -> Option<Self> {
+                match value {
+                    $($value => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns `true` for values not known to this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from_known(&value).unwrap_or_else(|| {
+                    tracing::warn!(
+                        enum_name = stringify!($name),
+                        %value,
+                        "Unknown value returned by the API"
+                    );
+                    $name::Unknown(value)
+                }))
+            }
+        }
+    };
+}
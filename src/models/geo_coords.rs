@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Geographic coordinates (WGS84).
 ///
 /// Represents a location on Earth using latitude and longitude in decimal degrees.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct GeoCoords {
     /// Latitude in decimal degrees (-90 to +90)
     pub latitude: f64,
@@ -4,124 +4,256 @@
 //! categories, characteristics, prices, and localization information.
 
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use std::collections::BTreeMap;
+use std::str::FromStr;
 
-use crate::models::address::Address;
-use crate::models::realestate::OfferType;
+use crate::models::address::{Address, PostalAddress};
+use crate::models::money::Money;
+use crate::models::realestate::{OfferType, PriceUnit};
 
-/// Property category classification.
-///
-/// Homegate categorizes properties into various types. This enum covers all
-/// supported property categories from apartments to specialized rooms.
-///
-/// # Categories
-///
-/// ## Apartments
-/// - `Apartment` - Standard apartment
-/// - `Flat` - Generic flat
-/// - `Maisonette` - Multi-level apartment
-/// - `Duplex` - Two-level apartment
-/// - `AtticFlat` - Apartment in the attic
-/// - `RoofFlat` - Apartment with roof access
-/// - `Studio` - Single-room apartment
-/// - `SingleRoom` - Individual room
-/// - `TerraceFlat` - Apartment with terrace
-/// - `BachelorFlat` - Small one-person apartment
-/// - `Loft` - Open-plan apartment
-/// - `Attic` - Attic space
-/// - `FurnishedFlat` - Fully furnished apartment
-///
-/// ## Houses
-/// - `House` - Generic house
-/// - `RowHouse` - Terraced house
-/// - `BifamiliarHouse` - Two-family house
-/// - `TerraceHouse` - House with terrace
-/// - `Villa` - Detached villa
-/// - `FarmHouse` - Rural farmhouse
-/// - `CaveHouse` - Underground dwelling
-/// - `Castle` - Castle or manor
-/// - `GrannyFlat` - Self-contained unit
-/// - `Chalet` - Mountain chalet
-/// - `Rustico` - Traditional stone house
-/// - `SingleHouse` - Detached single-family home
-///
-/// ## Other
-/// - `HobbyRoom` - Hobby or utility room
-/// - `CellarCompartment` - Cellar storage
-/// - `AtticCompartment` - Attic storage
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Category {
-    Flat,
-    Apartment,
-    Maisonette,
-    Duplex,
-    AtticFlat,
-    RoofFlat,
-    Studio,
-    SingleRoom,
-    TerraceFlat,
-    BachelorFlat,
-    Loft,
-    Attic,
-    House,
-    RowHouse,
-    BifamiliarHouse,
-    TerraceHouse,
-    Villa,
-    FarmHouse,
-    CaveHouse,
-    Castle,
-    GrannyFlat,
-    Chalet,
-    Rustico,
-    SingleHouse,
-    HobbyRoom,
-    CellarCompartment,
-    AtticCompartment,
-    FurnishedFlat,
-}
-
-impl Display for Category {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Serialize::serialize(self, f)
+api_enum! {
+    /// Property category classification.
+    ///
+    /// Homegate categorizes properties into various types. This enum covers all
+    /// supported property categories from apartments to specialized rooms.
+    ///
+    /// # Categories
+    ///
+    /// ## Apartments
+    /// - `Apartment` - Standard apartment
+    /// - `Flat` - Generic flat
+    /// - `Maisonette` - Multi-level apartment
+    /// - `Duplex` - Two-level apartment
+    /// - `AtticFlat` - Apartment in the attic
+    /// - `RoofFlat` - Apartment with roof access
+    /// - `Studio` - Single-room apartment
+    /// - `SingleRoom` - Individual room
+    /// - `TerraceFlat` - Apartment with terrace
+    /// - `BachelorFlat` - Small one-person apartment
+    /// - `Loft` - Open-plan apartment
+    /// - `Attic` - Attic space
+    /// - `FurnishedFlat` - Fully furnished apartment
+    ///
+    /// ## Houses
+    /// - `House` - Generic house
+    /// - `RowHouse` - Terraced house
+    /// - `BifamiliarHouse` - Two-family house
+    /// - `TerraceHouse` - House with terrace
+    /// - `Villa` - Detached villa
+    /// - `FarmHouse` - Rural farmhouse
+    /// - `CaveHouse` - Underground dwelling
+    /// - `Castle` - Castle or manor
+    /// - `GrannyFlat` - Self-contained unit
+    /// - `Chalet` - Mountain chalet
+    /// - `Rustico` - Traditional stone house
+    /// - `SingleHouse` - Detached single-family home
+    ///
+    /// ## Other
+    /// - `HobbyRoom` - Hobby or utility room
+    /// - `CellarCompartment` - Cellar storage
+    /// - `AtticCompartment` - Attic storage
+    pub enum Category {
+        Flat = "FLAT",
+        Apartment = "APARTMENT",
+        Maisonette = "MAISONETTE",
+        Duplex = "DUPLEX",
+        AtticFlat = "ATTIC_FLAT",
+        RoofFlat = "ROOF_FLAT",
+        Studio = "STUDIO",
+        SingleRoom = "SINGLE_ROOM",
+        TerraceFlat = "TERRACE_FLAT",
+        BachelorFlat = "BACHELOR_FLAT",
+        Loft = "LOFT",
+        Attic = "ATTIC",
+        House = "HOUSE",
+        RowHouse = "ROW_HOUSE",
+        BifamiliarHouse = "BIFAMILIAR_HOUSE",
+        TerraceHouse = "TERRACE_HOUSE",
+        Villa = "VILLA",
+        FarmHouse = "FARM_HOUSE",
+        CaveHouse = "CAVE_HOUSE",
+        Castle = "CASTLE",
+        GrannyFlat = "GRANNY_FLAT",
+        Chalet = "CHALET",
+        Rustico = "RUSTICO",
+        SingleHouse = "SINGLE_HOUSE",
+        HobbyRoom = "HOBBY_ROOM",
+        CellarCompartment = "CELLAR_COMPARTMENT",
+        AtticCompartment = "ATTIC_COMPARTMENT",
+        FurnishedFlat = "FURNISHED_FLAT",
+    }
+}
+
+impl FromStr for Category {
+    type Err = String;
+
+    /// Parses a category name case-insensitively, accepting `-` in place of `_`
+    /// (e.g. `attic-flat`, `ATTIC_FLAT`). Unknown names are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_uppercase().replace('-', "_");
+        Category::from_known(&normalized).ok_or_else(|| format!("Unknown category: {}", s))
     }
 }
 
 /// Property characteristics.
 ///
-/// Contains key physical attributes of a property.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Contains the physical attributes and amenities of a property. Every field is
+/// optional: search results only carry the sizes and room count, and houses,
+/// parking spaces or storage rooms lack some of them. Characteristics without a
+/// typed field are kept in [`Characteristics::extra`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Characteristics {
     /// Living space in square meters
-    pub living_space: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub living_space: Option<u32>,
     /// Number of rooms (can be fractional, e.g., 2.5 rooms)
-    pub number_of_rooms: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_rooms: Option<f32>,
+    /// Floor the property is on (0 is the ground floor, negative values are basements)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floor: Option<i32>,
+    /// Number of floors of the building
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_floors: Option<u32>,
+    /// Lot size in square meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lot_size: Option<u32>,
+    /// Floor space of a single floor in square meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub single_floor_space: Option<u32>,
+    /// Total floor space in square meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_floor_space: Option<u32>,
+    /// Year of construction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year_built: Option<u32>,
+    /// Year of the last renovation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year_last_renovated: Option<u32>,
+    /// Whether the property has a balcony or terrace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_balcony: Option<bool>,
+    /// Whether the building has an elevator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_elevator: Option<bool>,
+    /// Whether a parking space is available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_parking: Option<bool>,
+    /// Whether a garage is available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_garage: Option<bool>,
+    /// Whether pets are allowed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub are_pets_allowed: Option<bool>,
+    /// Whether the property is wheelchair accessible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_wheelchair_accessible: Option<bool>,
+    /// Whether the property is a new building
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_new_building: Option<bool>,
+    /// Other characteristics, keyed by their API name
+    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl Characteristics {
+    /// Returns the area prices per square meter refer to.
+    ///
+    /// This is the living space, falling back to the total and then the single
+    /// floor space for commercial properties, which usually have no living space.
+    pub fn floor_area(&self) -> Option<u32> {
+        self.living_space
+            .or(self.total_floor_space)
+            .or(self.single_floor_space)
+    }
 }
 
 /// Information about the property lister.
 ///
 /// Contains contact information for the person or agency listing the property.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Search results usually only carry the phone number and logo; the agency's name
+/// and address are often only available from
+/// [`RealEstate::lister`](crate::models::realestate::RealEstate::lister), which
+/// merges in the lister branding.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Lister {
+    /// Agency identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name of the agency or person
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// Contact phone number
     pub phone: Option<String>,
+    /// URL of the agency's logo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// Postal address of the agency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<PostalAddress>,
+    /// Website of the agency
+    #[serde(default, alias = "websiteUrl", skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
+
+impl Lister {
+    /// Returns a key identifying the lister across listings, if it has any
+    /// identifying information.
+    ///
+    /// The agency ID is preferred, followed by the name, phone number and logo.
+    pub fn key(&self) -> Option<String> {
+        let non_empty = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        non_empty(&self.id)
+            .map(|id| format!("id:{}", id))
+            .or_else(|| non_empty(&self.name).map(|name| format!("name:{}", name.to_lowercase())))
+            .or_else(|| {
+                non_empty(&self.phone).map(|phone| {
+                    let digits: String = phone.chars().filter(char::is_ascii_digit).collect();
+                    format!("phone:{}", digits)
+                })
+            })
+            .or_else(|| non_empty(&self.logo_url).map(|logo| format!("logo:{}", logo)))
+    }
+}
+
+api_enum! {
+    /// Kind of media attached to a listing.
+    pub enum AttachmentKind {
+        /// Photo of the property
+        Image = "IMAGE",
+        /// Floor plan
+        FloorPlan = "FLOOR_PLAN",
+        /// Document, e.g. a PDF brochure or application form
+        Document = "DOCUMENT",
+        /// Video of the property
+        Video = "VIDEO",
+        /// Link to a virtual tour
+        VirtualTour = "VIRTUAL_TOUR",
+    }
 }
 
 /// Attachment (image, document, etc.) associated with a listing.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachment {
-    /// Type of attachment (e.g., "IMAGE")
+    /// Kind of attachment (image, floor plan, etc.)
     #[serde(rename = "type")]
-    pub t: String,
+    pub t: AttachmentKind,
     /// URL to access the attachment
     pub url: String,
     /// Filename of the attachment
     pub file: String,
+    /// Caption of the attachment (only included in listing details)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Text content for a localized listing entry.
@@ -130,6 +262,9 @@ pub struct Attachment {
 pub struct LocalizationEntryText {
     /// Listing title in the specific language
     pub title: String,
+    /// Full description (only included in listing details)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// Localized content for a listing in a specific language.
@@ -142,6 +277,59 @@ pub struct LocalizationEntry {
     pub text: LocalizationEntryText,
 }
 
+/// Language of a localized listing entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    /// German
+    De,
+    /// English
+    En,
+    /// French
+    Fr,
+    /// Italian
+    It,
+}
+
+impl Lang {
+    /// Every supported language, in the order used by [`Localization::entries`].
+    pub const ALL: [Lang; 4] = [Lang::De, Lang::En, Lang::Fr, Lang::It];
+
+    /// Returns the two-letter language code, e.g. `de`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::De => "de",
+            Lang::En => "en",
+            Lang::Fr => "fr",
+            Lang::It => "it",
+        }
+    }
+}
+
+impl std::fmt::Display for Lang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    /// Parses a two-letter language code case-insensitively (`de`, `en`, `fr`, `it`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "de" => Ok(Lang::De),
+            "en" => Ok(Lang::En),
+            "fr" => Ok(Lang::Fr),
+            "it" => Ok(Lang::It),
+            other => Err(format!(
+                "Unknown language: {}. Supported: de, en, fr, it",
+                other
+            )),
+        }
+    }
+}
+
 /// Multi-language localization data for a listing.
 ///
 /// Homegate supports multiple languages (German, French, Italian, English).
@@ -149,22 +337,120 @@ pub struct LocalizationEntry {
 #[serde(rename_all = "camelCase")]
 pub struct Localization {
     /// German localization (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub de: Option<LocalizationEntry>,
+    /// English localization (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub en: Option<LocalizationEntry>,
+    /// French localization (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fr: Option<LocalizationEntry>,
+    /// Italian localization (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub it: Option<LocalizationEntry>,
     /// Primary language code for this listing
     pub primary: String,
 }
 
-/// Price interval enumeration.
-///
-/// Specifies how often a price applies (monthly, weekly, etc.).
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum PriceInterval {
-    /// Monthly payment
-    MONTH,
+impl Localization {
+    /// Returns all available localizations (German, English, French, Italian order).
+    pub fn entries(&self) -> impl Iterator<Item = &LocalizationEntry> {
+        [&self.de, &self.en, &self.fr, &self.it]
+            .into_iter()
+            .filter_map(Option::as_ref)
+    }
+
+    /// Returns the localization in the given language, if available.
+    pub fn get(&self, lang: Lang) -> Option<&LocalizationEntry> {
+        match lang {
+            Lang::De => self.de.as_ref(),
+            Lang::En => self.en.as_ref(),
+            Lang::Fr => self.fr.as_ref(),
+            Lang::It => self.it.as_ref(),
+        }
+    }
+
+    /// Returns the primary language of the listing, if it is a supported one.
+    pub fn primary_lang(&self) -> Option<Lang> {
+        self.primary.parse().ok()
+    }
+
+    /// Returns the localization best matching the preferred languages.
+    ///
+    /// Falls back to the listing's primary language, then to any available
+    /// localization, so a listing published only in French or Italian still
+    /// resolves when German is preferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred` - Languages in order of preference, may be empty
+    ///
+    /// # Returns
+    ///
+    /// The matching entry, or `None` if the listing has no localization at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use homegate::models::listing::{Lang, Localization};
+    ///
+    /// let localization: Localization = serde_json::from_str(
+    ///     r#"{"fr":{"attachments":[],"text":{"title":"Appartement lumineux"}},"primary":"fr"}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let entry = localization.resolve(&[Lang::De, Lang::En]).unwrap();
+    /// assert_eq!(entry.text.title, "Appartement lumineux");
+    /// ```
+    pub fn resolve(&self, preferred: &[Lang]) -> Option<&LocalizationEntry> {
+        preferred
+            .iter()
+            .chain(self.primary_lang().as_ref())
+            .find_map(|&lang| self.get(lang))
+            .or_else(|| self.entries().next())
+    }
+
+    /// Returns all attachments across every language, without duplicates.
+    ///
+    /// Attachments are deduplicated by URL; the first language carrying an
+    /// attachment (German, English, French, Italian order) provides its title.
+    pub fn attachments(&self) -> Vec<&Attachment> {
+        let mut attachments: Vec<&Attachment> = Vec::new();
+        for attachment in self.entries().flat_map(|e| &e.attachments) {
+            if !attachments.iter().any(|a| a.url == attachment.url) {
+                attachments.push(attachment);
+            }
+        }
+        attachments
+    }
+
+    /// Returns the attachments of the given kind, without duplicates.
+    pub fn attachments_of(&self, kind: &AttachmentKind) -> Vec<&Attachment> {
+        self.attachments()
+            .into_iter()
+            .filter(|a| &a.t == kind)
+            .collect()
+    }
+}
+
+api_enum! {
+    /// Price interval enumeration.
+    ///
+    /// Specifies how often a price applies (monthly, weekly, etc.).
+    pub enum PriceInterval {
+        /// Weekly payment
+        WEEK = "WEEK",
+        /// Monthly payment
+        MONTH = "MONTH",
+        /// Yearly payment
+        YEAR = "YEAR",
+    }
 }
 
 /// Price information for rent or purchase.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Purchase prices are one-off amounts and usually have no `interval`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Price {
     /// Payment interval (monthly, weekly, etc.)
@@ -175,20 +461,72 @@ pub struct Price {
     pub gross: Option<u32>,
     /// Extra costs (utilities, etc.)
     pub extra: Option<u32>,
+    /// Unit the amounts are expressed in, e.g. per m² per year for commercial
+    /// properties. Takes precedence over `interval` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<PriceUnit>,
 }
 
-/// Currency enumeration.
-///
-/// Currently only Swiss Francs are supported.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Currency {
-    /// Swiss Francs
-    CHF,
+impl Price {
+    /// Returns the gross price, falling back to the net price.
+    pub fn amount(&self) -> Option<u32> {
+        self.gross.or(self.net)
+    }
+
+    /// Returns the gross price, falling back to the net price plus extra costs when
+    /// both are known.
+    ///
+    /// Prices per m² have no such fallback: their net price is per m² while extra
+    /// costs are absolute amounts, so the two cannot be added as they are.
+    pub fn gross_amount(&self) -> Option<u32> {
+        if self.unit == Some(PriceUnit::M2YEARLY) {
+            return self.gross;
+        }
+        self.gross
+            .or_else(|| Some(self.net?.saturating_add(self.extra?)))
+    }
+
+    /// Converts an amount of this price to a monthly amount.
+    ///
+    /// Weekly amounts are converted with 52 weeks per year, and amounts per m² per
+    /// year need the area of the property. Prices without unit or interval are
+    /// taken to be monthly.
+    fn to_monthly(&self, amount: Money, area: Option<u32>) -> Option<Money> {
+        match &self.unit {
+            Some(PriceUnit::M2YEARLY) => amount.scale(u64::from(area.filter(|&a| a > 0)?), 12),
+            Some(PriceUnit::WEEKLY) => amount.scale(52, 12),
+            Some(PriceUnit::MONTHLY) => Some(amount),
+            Some(PriceUnit::Unknown(_)) => None,
+            None => self.interval_to_monthly(amount),
+        }
+    }
+
+    /// Converts an absolute amount of this price, such as extra costs, to a monthly
+    /// amount using only its interval.
+    fn interval_to_monthly(&self, amount: Money) -> Option<Money> {
+        match &self.interval {
+            Some(PriceInterval::WEEK) => amount.scale(52, 12),
+            Some(PriceInterval::MONTH) | None => Some(amount),
+            Some(PriceInterval::YEAR) => amount.scale(1, 12),
+            Some(PriceInterval::Unknown(_)) => None,
+        }
+    }
+}
+
+api_enum! {
+    /// Currency enumeration.
+    ///
+    /// Swiss Francs are the only currency known to be used.
+    pub enum Currency {
+        /// Swiss Francs
+        CHF = "CHF",
+    }
 }
 
 /// Complete pricing information for a listing.
 ///
-/// Includes both rental and purchase prices (though typically only one applies).
+/// Includes both rental and purchase prices (though typically only one applies,
+/// see [`Prices::for_offer_type`]).
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Prices {
@@ -200,6 +538,89 @@ pub struct Prices {
     pub buy: Option<Price>,
 }
 
+impl Prices {
+    /// Returns the price that applies to the given offer type.
+    ///
+    /// This is the rental price for `RENT` listings and the purchase price for
+    /// `BUY` listings, and `None` for unknown offer types.
+    pub fn for_offer_type(&self, offer_type: &OfferType) -> Option<&Price> {
+        match offer_type {
+            OfferType::RENT => self.rent.as_ref(),
+            OfferType::BUY => self.buy.as_ref(),
+            OfferType::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the gross rent per month, whatever interval or unit it is quoted in.
+    ///
+    /// Weekly and yearly rents are converted to monthly ones, and rents per m² per
+    /// year are multiplied by the area. Without a gross rent, the net rent and the
+    /// extra costs are converted separately and added. This makes residential,
+    /// commercial and parking listings comparable.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Area of the property in m², only needed for prices per m²
+    ///   (see [`Characteristics::floor_area`])
+    ///
+    /// # Returns
+    ///
+    /// The monthly gross rent, or `None` if there is no rent, its interval or unit
+    /// is unknown, or it is quoted per m² and no area is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use homegate::models::listing::Prices;
+    ///
+    /// let prices: Prices = serde_json::from_str(
+    ///     r#"{"rent":{"gross":240,"unit":"M2YEARLY"},"currency":"CHF"}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let monthly = prices.normalized_monthly_gross(Some(150)).unwrap();
+    /// assert_eq!(monthly.to_string(), "CHF 3000.00");
+    /// ```
+    pub fn normalized_monthly_gross(&self, area: Option<u32>) -> Option<Money> {
+        let rent = self.rent.as_ref()?;
+        if let Some(gross) = rent.gross_amount() {
+            return rent.to_monthly(Money::new(gross, self.currency.clone()), area);
+        }
+        let net = self.normalized_monthly_net(area)?;
+        let extra = rent.interval_to_monthly(Money::new(rent.extra?, self.currency.clone()))?;
+        net.checked_add(&extra)
+    }
+
+    /// Returns the net rent per month, whatever interval or unit it is quoted in.
+    ///
+    /// See [`Prices::normalized_monthly_gross`].
+    pub fn normalized_monthly_net(&self, area: Option<u32>) -> Option<Money> {
+        let rent = self.rent.as_ref()?;
+        rent.to_monthly(Money::new(rent.net?, self.currency.clone()), area)
+    }
+
+    /// Returns the purchase price.
+    pub fn purchase_price(&self) -> Option<Money> {
+        let amount = self.buy.as_ref()?.amount()?;
+        Some(Money::new(amount, self.currency.clone()))
+    }
+
+    /// Returns the monthly gross rent per m².
+    ///
+    /// See [`Prices::normalized_monthly_gross`].
+    pub fn monthly_gross_per_m2(&self, area: Option<u32>) -> Option<Money> {
+        let area = area.filter(|&a| a > 0)?;
+        self.normalized_monthly_gross(Some(area))?
+            .scale(1, u64::from(area))
+    }
+
+    /// Returns the purchase price per m².
+    pub fn purchase_price_per_m2(&self, area: Option<u32>) -> Option<Money> {
+        let area = area.filter(|&a| a > 0)?;
+        self.purchase_price()?.scale(1, u64::from(area))
+    }
+}
+
 /// Complete real estate listing information.
 ///
 /// This is the main structure containing all details about a property listing
@@ -212,6 +633,7 @@ pub struct Listing {
     /// Property categories (apartment, house, etc.)
     pub categories: Vec<Category>,
     /// Physical characteristics (size, rooms)
+    #[serde(default)]
     pub characteristics: Characteristics,
     /// Unique listing identifier
     pub id: String,
@@ -224,3 +646,43 @@ pub struct Listing {
     /// Pricing information
     pub prices: Prices,
 }
+
+impl Listing {
+    /// Returns the gross rent per month, see [`Prices::normalized_monthly_gross`].
+    pub fn normalized_monthly_gross(&self) -> Option<Money> {
+        self.prices
+            .normalized_monthly_gross(self.characteristics.floor_area())
+    }
+
+    /// Returns the net rent per month, see [`Prices::normalized_monthly_net`].
+    pub fn normalized_monthly_net(&self) -> Option<Money> {
+        self.prices
+            .normalized_monthly_net(self.characteristics.floor_area())
+    }
+
+    /// Returns the price per m²: the monthly gross rent for rentals, the purchase
+    /// price for sales.
+    pub fn price_per_m2(&self) -> Option<Money> {
+        let area = self.characteristics.floor_area();
+        match self.offer_type {
+            OfferType::RENT => self.prices.monthly_gross_per_m2(area),
+            OfferType::BUY => self.prices.purchase_price_per_m2(area),
+            OfferType::Unknown(_) => None,
+        }
+    }
+
+    /// Returns all attachments across every language, without duplicates.
+    pub fn attachments(&self) -> Vec<&Attachment> {
+        self.localization.attachments()
+    }
+
+    /// Returns the photos of the property.
+    pub fn images(&self) -> Vec<&Attachment> {
+        self.localization.attachments_of(&AttachmentKind::Image)
+    }
+
+    /// Returns the floor plans of the property.
+    pub fn floor_plans(&self) -> Vec<&Attachment> {
+        self.localization.attachments_of(&AttachmentKind::FloorPlan)
+    }
+}
@@ -0,0 +1,85 @@
+//! Full listing details.
+//!
+//! This module defines the detailed listing structure returned when fetching a
+//! single listing by id, as opposed to the trimmed summaries in search results.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::models::address::Address;
+use crate::models::listing::{
+    Attachment, AttachmentKind, Category, Characteristics, Lister, Localization, Prices,
+};
+use crate::models::realestate::OfferType;
+
+/// Complete details of a single listing.
+///
+/// Returned by [`HomegateClient::get_listing`](crate::api::request::HomegateClient::get_listing).
+/// Unlike [`Listing`](crate::models::listing::Listing), this includes the full
+/// description in every language, every characteristic, the availability date,
+/// features, all attachments and the lister's contact information.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingDetail {
+    /// Unique listing identifier
+    pub id: String,
+    /// Type of offer (rent, buy)
+    pub offer_type: OfferType,
+    /// Property categories (apartment, house, etc.)
+    pub categories: Vec<Category>,
+    /// Property address
+    pub address: Address,
+    /// Pricing information
+    pub prices: Prices,
+    /// Physical attributes and amenities of the property
+    #[serde(default)]
+    pub characteristics: Characteristics,
+    /// Multi-language content, including full descriptions and attachments
+    pub localization: Localization,
+    /// Lister contact information
+    pub lister: Option<Lister>,
+    /// Date from which the property is available, if known
+    #[serde(default, deserialize_with = "deserialize_date_lenient")]
+    pub available_from: Option<NaiveDate>,
+    /// Amenities and features (e.g. "BALCONY", "ELEVATOR")
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+impl ListingDetail {
+    /// Returns all attachments across every language, without duplicates.
+    pub fn attachments(&self) -> Vec<&Attachment> {
+        self.localization.attachments()
+    }
+
+    /// Returns the photos of the property.
+    pub fn images(&self) -> Vec<&Attachment> {
+        self.localization.attachments_of(&AttachmentKind::Image)
+    }
+
+    /// Returns the floor plans of the property.
+    pub fn floor_plans(&self) -> Vec<&Attachment> {
+        self.localization.attachments_of(&AttachmentKind::FloorPlan)
+    }
+}
+
+/// Deserializes an ISO 8601 date, local date-time or RFC 3339 date-time into its date.
+///
+/// Values that are not a valid date are logged and treated as missing, so an
+/// unexpected format does not make the whole listing unreadable.
+fn deserialize_date_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDate>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| {
+        let date = value
+            .parse::<NaiveDate>()
+            .or_else(|_| value.parse::<NaiveDateTime>().map(|t| t.date()))
+            .or_else(|_| DateTime::parse_from_rfc3339(&value).map(|t| t.date_naive()))
+            .ok();
+        if date.is_none() {
+            tracing::warn!(value = %value, "Ignoring malformed availability date");
+        }
+        date
+    }))
+}
@@ -1,24 +1,38 @@
 //! Location metadata structures.
 //!
-//! This module defines location information structures used by the Homegate API.
+//! This module defines location information structures used by the Homegate API,
+//! as returned by the geo lookup endpoint.
 
 use serde::{Deserialize, Serialize};
 
+use crate::models::geo_coords::GeoCoords;
+
 /// Location metadata.
 ///
-/// Represents a named location with type information (e.g., city, region).
-#[derive(Serialize, Deserialize, Debug)]
+/// Represents a named location with type information (e.g., city, region), as
+/// resolved from free text by [`HomegateClient::geo_lookup`](crate::api::request::HomegateClient::geo_lookup).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Location {
+    /// Geo tag identifying the location (e.g., "geo-city-winterthur")
+    id: String,
     /// Location name (e.g., "Zürich")
     name: String,
     /// Location type identifier
     r#type: String,
     /// Human-readable location type label
-    #[serde(rename = "typeLabel")]
     type_label: String,
+    /// Center of the location (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    geo_coordinates: Option<GeoCoords>,
 }
 
 impl Location {
+    /// Returns the geo tag identifying the location (e.g., "geo-city-winterthur")
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     /// Returns the location name (e.g., "Zürich")
     pub fn name(&self) -> &str {
         &self.name
@@ -33,4 +47,16 @@ impl Location {
     pub fn type_label(&self) -> &str {
         &self.type_label
     }
+
+    /// Returns the center of the location (if available)
+    pub fn geo_coordinates(&self) -> Option<&GeoCoords> {
+        self.geo_coordinates.as_ref()
+    }
+}
+
+/// Location suggestions returned by the geo lookup endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LocationSuggestions {
+    /// Matching locations, best match first
+    pub results: Vec<Location>,
 }
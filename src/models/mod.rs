@@ -4,9 +4,14 @@
 //! listings, addresses, geographic coordinates, and paginated results from the
 //! Homegate API.
 
+#[macro_use]
+mod api_enum;
+
 pub mod address;
 pub mod geo_coords;
 pub mod listing;
+pub mod listing_detail;
 pub mod location;
+pub mod money;
 pub mod paginated;
 pub mod realestate;
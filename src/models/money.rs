@@ -0,0 +1,106 @@
+//! Monetary amounts.
+//!
+//! This module defines [`Money`], an amount tied to its [`Currency`], used for
+//! prices derived from the raw amounts returned by the API (e.g. monthly rents
+//! normalized from weekly prices, or prices per square meter).
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::listing::Currency;
+
+/// An amount of money in a currency.
+///
+/// Amounts are stored in hundredths of the currency unit (Rappen for CHF), so
+/// derived prices such as a monthly rent computed from a weekly one keep their
+/// precision without floating point rounding errors accumulating.
+///
+/// Amounts in different currencies are never equal and cannot be ordered or added.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::models::listing::Currency;
+/// use homegate::models::money::Money;
+///
+/// let rent = Money::new(2_500, Currency::CHF);
+/// let parking = Money::from_cents(15_050, Currency::CHF);
+///
+/// let total = rent.checked_add(&parking).unwrap();
+/// assert_eq!(total.to_string(), "CHF 2650.50");
+/// assert!(parking < rent);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct Money {
+    /// Amount in hundredths of the currency unit
+    pub cents: u64,
+    /// Currency of the amount
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of whole currency units, e.g. `2500` CHF.
+    pub fn new(amount: u32, currency: Currency) -> Self {
+        Self::from_cents(u64::from(amount) * 100, currency)
+    }
+
+    /// Creates an amount from hundredths of the currency unit, e.g. Rappen.
+    pub fn from_cents(cents: u64, currency: Currency) -> Self {
+        Self { cents, currency }
+    }
+
+    /// Returns the amount in currency units, e.g. `2650.5` for CHF 2650.50.
+    pub fn amount(&self) -> f64 {
+        self.cents as f64 / 100.0
+    }
+
+    /// Returns the amount in whole currency units, rounded to the nearest unit.
+    pub fn rounded(&self) -> u64 {
+        self.cents.saturating_add(50) / 100
+    }
+
+    /// Adds two amounts, or returns `None` if their currencies differ or the sum
+    /// overflows.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        let cents = self.cents.checked_add(other.cents)?;
+        Some(Money::from_cents(cents, self.currency.clone()))
+    }
+
+    /// Multiplies the amount by `numerator / denominator`, rounding to the nearest
+    /// cent, or returns `None` if `denominator` is zero.
+    pub(crate) fn scale(&self, numerator: u64, denominator: u64) -> Option<Money> {
+        if denominator == 0 {
+            return None;
+        }
+        let cents = (u128::from(self.cents) * u128::from(numerator) + u128::from(denominator) / 2)
+            / u128::from(denominator);
+        Some(Money::from_cents(
+            u64::try_from(cents).ok()?,
+            self.currency.clone(),
+        ))
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.currency == other.currency).then(|| self.cents.cmp(&other.cents))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}.{:02}",
+            self.currency,
+            self.cents / 100,
+            self.cents % 100
+        )
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt;
+
 use crate::models::realestate::RealEstate;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Paginated response container for API results.
 ///
@@ -18,6 +22,31 @@ pub struct Paginated<T> {
     pub size: u32,
     /// Total number of items across all pages
     pub total: u32,
+    /// Results dropped by lenient parsing because they could not be parsed
+    #[serde(skip)]
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// A result that lenient parsing could not deserialize and left out of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedItem {
+    /// Position of the item in the `results` array
+    pub index: usize,
+    /// Listing ID, if the item has one
+    pub id: Option<String>,
+    /// JSON path of the offending value, e.g. `results[3].listing.characteristics.livingSpace`
+    pub path: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for SkippedItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "listing {} at {}: {}", id, self.path, self.message),
+            None => write!(f, "result at {}: {}", self.path, self.message),
+        }
+    }
 }
 
 /// Parses a search result JSON string into a paginated real estate listing.
@@ -37,6 +66,84 @@ pub fn parse_search_result(str: &str) -> crate::Result<Paginated<RealEstate>> {
     serde_json::from_str(str).map_err(Into::into)
 }
 
+/// Parses a search result JSON string, skipping results that cannot be parsed.
+///
+/// Unlike [`parse_search_result`], a single malformed listing does not discard the
+/// whole page: each result is deserialized on its own and failures are reported in
+/// [`Paginated::skipped`].
+///
+/// # Arguments
+///
+/// * `str` - JSON string containing the search results
+///
+/// # Returns
+///
+/// Returns a `Paginated<RealEstate>` holding the results that could be parsed.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is invalid or the page itself (e.g. `total`)
+/// cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use homegate::models::paginated::parse_search_result_lenient;
+///
+/// let json = r#"{"from":0,"maxFrom":0,"size":20,"total":1,"results":[{"id":"1"}]}"#;
+/// let page = parse_search_result_lenient(json).unwrap();
+/// assert!(page.results.is_empty());
+/// assert_eq!(page.skipped[0].id.as_deref(), Some("1"));
+/// assert_eq!(page.skipped[0].message, "missing field `listing`");
+/// ```
+pub fn parse_search_result_lenient(str: &str) -> crate::Result<Paginated<RealEstate>> {
+    parse_lenient(str)
+}
+
+fn parse_lenient<T: DeserializeOwned>(str: &str) -> crate::Result<Paginated<T>> {
+    let page: Paginated<Value> = serde_json::from_str(str)?;
+    let mut results = Vec::with_capacity(page.results.len());
+    let mut skipped = Vec::new();
+
+    for (index, item) in page.results.into_iter().enumerate() {
+        match serde_path_to_error::deserialize::<_, T>(&item) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                let path = match e.path().to_string().as_str() {
+                    "." => format!("results[{}]", index),
+                    path => format!("results[{}].{}", index, path),
+                };
+                skipped.push(SkippedItem {
+                    index,
+                    id: item_id(&item),
+                    path,
+                    message: e.into_inner().to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(Paginated {
+        from: page.from,
+        max_from: page.max_from,
+        results,
+        size: page.size,
+        total: page.total,
+        skipped,
+    })
+}
+
+/// Extracts the listing ID of a raw result, if present.
+fn item_id(item: &Value) -> Option<String> {
+    [&item["id"], &item["listing"]["id"]]
+        .into_iter()
+        .find_map(|id| match id {
+            Value::String(id) => Some(id.clone()),
+            Value::Number(id) => Some(id.to_string()),
+            _ => None,
+        })
+}
+
 #[cfg(test)]
 mod test {
     use crate::models::paginated::parse_search_result;
@@ -3,9 +3,12 @@
 //! This module defines the top-level real estate structure and various
 //! enumeration types for offer types, pricing units, and listing tiers.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
-use crate::models::listing::Listing;
+use crate::models::address::PostalAddress;
+use crate::models::listing::{Lister, Listing};
 
 /// Real estate listing container.
 ///
@@ -18,6 +21,128 @@ pub struct RealEstate {
     pub id: String,
     /// Detailed listing information
     pub listing: Listing,
+    /// Placement tier of the listing, if returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listing_type: Option<ListingTypeWrapper>,
+    /// Whether the listing offers a remote viewing (virtual tour)
+    #[serde(default)]
+    pub remote_viewing: bool,
+    /// Branding of the agency publishing the listing, if returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lister_branding: Option<ListerBranding>,
+}
+
+impl RealEstate {
+    /// Returns the placement tier of the listing, if known.
+    pub fn listing_type(&self) -> Option<&ListingType> {
+        self.listing_type.as_ref().map(|wrapper| &wrapper.t)
+    }
+
+    /// Returns the lister, completed with the lister branding.
+    ///
+    /// Fields missing from [`Listing::lister`] are taken from
+    /// [`RealEstate::lister_branding`]: the legal name, logo and address.
+    pub fn lister(&self) -> Lister {
+        let mut lister = self.listing.lister.clone();
+        if let Some(branding) = &self.lister_branding {
+            lister.name = lister.name.or_else(|| branding.legal_name.clone());
+            lister.logo_url = lister.logo_url.or_else(|| branding.logo_url.clone());
+            lister.address = lister.address.or_else(|| branding.address.clone());
+        }
+        lister
+    }
+
+    /// Returns `true` if the listing is a paid placement (`PREMIUM` or `TOP`).
+    pub fn is_promoted(&self) -> bool {
+        matches!(
+            self.listing_type(),
+            Some(ListingType::PREMIUM | ListingType::TOP)
+        )
+    }
+}
+
+/// Listings published by the same lister.
+///
+/// Returned by [`group_by_lister`].
+#[derive(Debug, Clone)]
+pub struct ListerGroup<'a> {
+    /// Key identifying the lister (see [`Lister::key`]), or `None` for listings
+    /// without any lister information
+    pub key: Option<String>,
+    /// The lister, as returned with its first listing
+    pub lister: Lister,
+    /// The lister's listings, in their original order
+    pub listings: Vec<&'a RealEstate>,
+}
+
+/// Groups listings by the lister publishing them.
+///
+/// Listers are identified by [`Lister::key`] after merging in the lister branding,
+/// so the same agency is recognized across pages and searches. Groups are ordered
+/// by number of listings (largest first), then by first appearance, with listings
+/// without any lister information last.
+///
+/// # Arguments
+///
+/// * `results` - Listings to group, e.g. the results of one or more searches
+///
+/// # Returns
+///
+/// One group per lister, plus a group with no key for listings without any
+/// lister information.
+///
+/// # Examples
+///
+/// ```no_run
+/// use homegate::api::search::{search, Location};
+/// use homegate::models::realestate::group_by_lister;
+///
+/// # async fn example() -> homegate::Result<()> {
+/// let page = search(&Location { latitude: 47.36667, longitude: 8.55, radius: 1000 }).await?;
+/// for group in group_by_lister(&page.results) {
+///     println!("{:?}: {} listings", group.lister.name, group.listings.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn group_by_lister(results: &[RealEstate]) -> Vec<ListerGroup<'_>> {
+    let mut groups: Vec<ListerGroup<'_>> = Vec::new();
+    for real_estate in results {
+        let lister = real_estate.lister();
+        let key = lister.key();
+        match groups.iter_mut().find(|group| group.key == key) {
+            Some(group) => group.listings.push(real_estate),
+            None => groups.push(ListerGroup {
+                key,
+                lister,
+                listings: vec![real_estate],
+            }),
+        }
+    }
+    // Stable sort keeps the order of first appearance among equally sized groups
+    groups.sort_by_key(|group| (group.key.is_none(), std::cmp::Reverse(group.listings.len())));
+    groups
+}
+
+/// Branding of the agency publishing a listing.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ListerBranding {
+    /// Legal name of the agency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<String>,
+    /// URL of the agency's logo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// Postal address of the agency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<PostalAddress>,
+    /// Whether the agency is a Homegate quality partner
+    #[serde(default)]
+    pub is_quality_partner: bool,
+    /// Whether the agency's advertising is active
+    #[serde(default)]
+    pub ad_active: bool,
 }
 
 /// Wrapper for listing type information.
@@ -28,38 +153,59 @@ pub struct ListingTypeWrapper {
     pub t: ListingType,
 }
 
-/// Type of real estate offer.
-///
-/// Indicates whether the property is for rent, sale, or other purpose.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub enum OfferType {
-    /// Property is available for rent
-    RENT,
+api_enum! {
+    /// Type of real estate offer.
+    ///
+    /// Indicates whether the property is for rent, sale, or other purpose.
+    pub enum OfferType {
+        /// Property is available for rent
+        RENT = "RENT",
+        /// Property is available for purchase
+        BUY = "BUY",
+    }
 }
 
-/// Price unit enumeration.
-///
-/// Specifies the unit used for pricing calculations.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub enum PriceUnit {
-    /// Price per month
-    MONTHLY,
-    /// Price per square meter per year
-    M2YEARLY,
-    /// Price per week
-    WEEKLY,
+impl FromStr for OfferType {
+    type Err = String;
+
+    /// Parses an offer type case-insensitively (`rent` or `buy`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "rent" => Ok(OfferType::RENT),
+            "buy" => Ok(OfferType::BUY),
+            other => Err(format!(
+                "Unknown offer type: {}. Supported: rent, buy",
+                other
+            )),
+        }
+    }
 }
 
-/// Listing tier/priority level.
-///
-/// Homegate uses different listing tiers that affect visibility and placement
-/// in search results.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub enum ListingType {
-    /// Premium tier listing (highest visibility)
-    PREMIUM,
-    /// Top tier listing (high visibility)
-    TOP,
-    /// Standard tier listing (normal visibility)
-    STANDARD,
+api_enum! {
+    /// Price unit enumeration.
+    ///
+    /// Specifies the unit used for pricing calculations.
+    pub enum PriceUnit {
+        /// Price per month
+        MONTHLY = "MONTHLY",
+        /// Price per square meter per year
+        M2YEARLY = "M2YEARLY",
+        /// Price per week
+        WEEKLY = "WEEKLY",
+    }
+}
+
+api_enum! {
+    /// Listing tier/priority level.
+    ///
+    /// Homegate uses different listing tiers that affect visibility and placement
+    /// in search results.
+    pub enum ListingType {
+        /// Premium tier listing (highest visibility)
+        PREMIUM = "PREMIUM",
+        /// Top tier listing (high visibility)
+        TOP = "TOP",
+        /// Standard tier listing (normal visibility)
+        STANDARD = "STANDARD",
+    }
 }
@@ -0,0 +1,502 @@
+//! Offline stand-in for the Homegate API.
+//!
+//! [`MockHomegate`] serves `/search/listings` from a fixture dataset such as
+//! `resources/test/result-*.json`, applying the same semantics as the real API:
+//! radius filtering, price, room and living space ranges, categories, sorting and
+//! paging with `from`/`size`/`maxFrom`. It can be plugged into a
+//! [`HomegateClient`] as a [`Transport`] or served over HTTP, which is what the
+//! `homegate mock-server` subcommand does.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use homegate::api::search::{Location, SearchQuery};
+//! use homegate::testing::MockHomegate;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = MockHomegate::from_dir("resources/test")?;
+//! let client = mock.client()?;
+//!
+//! let zurich = Location { latitude: 47.36667, longitude: 8.55, radius: 1000 };
+//! let results = client.search_with(&SearchQuery::new(zurich)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use reqwest::{Request, Response};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, ToSocketAddrs};
+use tokio::task::JoinHandle;
+
+use crate::api::request::HomegateClient;
+use crate::api::search::{
+    FromTo, FromToFloat, Query, SearchLocation, SearchRequest, SortBy, SortDirection,
+};
+use crate::api::transport::Transport;
+use crate::models::address::Address;
+use crate::models::listing::Listing;
+use crate::models::realestate::{ListingType, OfferType, RealEstate};
+
+/// Path of the search endpoint.
+const SEARCH_PATH: &str = "/search/listings";
+
+/// Mean Earth radius in meters, used for haversine distances.
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// In-memory Homegate API serving a fixture dataset.
+///
+/// Cloning is cheap; clones share the dataset.
+#[derive(Debug, Clone, Default)]
+pub struct MockHomegate {
+    listings: Arc<Vec<MockListing>>,
+}
+
+/// A listing of the dataset, kept as raw JSON so responses match the fixtures.
+#[derive(Debug)]
+struct MockListing {
+    real_estate: RealEstate,
+    raw: Value,
+}
+
+/// Response produced by [`MockHomegate::handle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// JSON response body
+    pub body: Value,
+}
+
+impl MockHomegate {
+    /// Creates a mock serving the given search results.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - Raw search result entries, as found in the `results` array of a
+    ///   search response
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if an entry is not a valid [`RealEstate`].
+    pub fn new(results: impl IntoIterator<Item = Value>) -> crate::Result<Self> {
+        let mut listings: Vec<MockListing> = Vec::new();
+        for raw in results {
+            let real_estate: RealEstate = serde_json::from_value(raw.clone())?;
+            // Fixtures may overlap, keep the first copy of each listing
+            if listings.iter().any(|l| l.real_estate.id == real_estate.id) {
+                continue;
+            }
+            listings.push(MockListing { real_estate, raw });
+        }
+        Ok(Self {
+            listings: Arc::new(listings),
+        })
+    }
+
+    /// Creates a mock from search response fixtures.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Files containing search responses, e.g. `resources/test/result-2.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or does not contain valid results.
+    pub fn from_fixtures<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> io::Result<Self> {
+        let mut results = Vec::new();
+        for path in paths {
+            let path = path.as_ref();
+            let invalid = |e: &dyn std::fmt::Display| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            };
+
+            let mut response: Value =
+                serde_json::from_str(&fs::read_to_string(path)?).map_err(|e| invalid(&e))?;
+            match response.get_mut("results").map(Value::take) {
+                Some(Value::Array(items)) => results.extend(items),
+                _ => return Err(invalid(&"missing \"results\" array")),
+            }
+        }
+        Self::new(results).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    /// Creates a mock from every `result-*.json` fixture in a directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or a fixture cannot be read.
+    pub fn from_dir(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_fixture = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("result-") && name.ends_with(".json"));
+            if is_fixture {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Self::from_fixtures(paths)
+    }
+
+    /// Returns the number of listings in the dataset.
+    pub fn len(&self) -> usize {
+        self.listings.len()
+    }
+
+    /// Returns `true` if the dataset is empty.
+    pub fn is_empty(&self) -> bool {
+        self.listings.is_empty()
+    }
+
+    /// Creates a [`HomegateClient`] sending its requests to this mock.
+    pub fn client(&self) -> crate::Result<HomegateClient> {
+        HomegateClient::builder().transport(self.clone()).build()
+    }
+
+    /// Answers a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method
+    /// * `path` - URL path, without the query string
+    /// * `body` - Request body
+    pub fn handle(&self, method: &str, path: &str, body: &[u8]) -> MockResponse {
+        match (method, path) {
+            ("POST", SEARCH_PATH) => match serde_json::from_slice::<SearchRequest>(body) {
+                Ok(request) => MockResponse {
+                    status: 200,
+                    body: self.search(&request),
+                },
+                Err(e) => MockResponse {
+                    status: 400,
+                    body: json!({ "message": format!("invalid search request: {}", e) }),
+                },
+            },
+            _ => MockResponse {
+                status: 404,
+                body: json!({ "message": format!("no route for {} {}", method, path) }),
+            },
+        }
+    }
+
+    /// Runs a search against the dataset and returns the response body.
+    pub fn search(&self, request: &SearchRequest) -> Value {
+        let query = &request.query;
+        let mut matches: Vec<(&MockListing, Option<f64>)> = self
+            .listings
+            .iter()
+            .filter(|l| matches_query(query, &l.real_estate.listing))
+            .map(|l| (l, distance(&query.location, &l.real_estate.listing.address)))
+            .collect();
+
+        matches.sort_by(|(a, a_dist), (b, b_dist)| {
+            let a = sort_key(request.sort_by, &a.real_estate, *a_dist);
+            let b = sort_key(request.sort_by, &b.real_estate, *b_dist);
+            compare_keys(a, b, request.sort_direction)
+        });
+
+        let total = matches.len();
+        let from = request.from.max(0) as usize;
+        let size = request.size.max(0) as usize;
+        let results: Vec<&Value> = matches
+            .iter()
+            .skip(from)
+            .take(size)
+            .map(|(l, _)| &l.raw)
+            .collect();
+
+        json!({
+            "from": from,
+            "size": size,
+            "total": total,
+            "maxFrom": max_from(total, size),
+            "results": results,
+        })
+    }
+
+    /// Serves the mock over HTTP in the background.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address to bind, e.g. `127.0.0.1:0` for a random port
+    ///
+    /// # Returns
+    ///
+    /// A handle exposing the bound address. The server stops when it is dropped.
+    pub async fn start(&self, addr: impl ToSocketAddrs) -> io::Result<MockHomegateServer> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let mock = self.clone();
+        let task = tokio::spawn(async move {
+            if let Err(e) = mock.serve(listener).await {
+                tracing::error!(error = %e, "Mock server stopped");
+            }
+        });
+        Ok(MockHomegateServer { addr, task })
+    }
+
+    /// Serves the mock over HTTP on the given listener until an error occurs.
+    pub async fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let mock = self.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |req| {
+                    let mock = mock.clone();
+                    async move { mock.handle_http(req).await }
+                });
+                if let Err(e) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    tracing::debug!(error = %e, "Mock server connection failed");
+                }
+            });
+        }
+    }
+
+    async fn handle_http(
+        &self,
+        req: hyper::Request<Incoming>,
+    ) -> Result<hyper::Response<Full<Bytes>>, Infallible> {
+        let method = req.method().to_string();
+        let path = req.uri().path().to_string();
+        let body = match req.into_body().collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(_) => Bytes::new(),
+        };
+
+        let response = self.handle(&method, &path, &body);
+        tracing::debug!(%method, %path, status = response.status, "Mock server request");
+        Ok(hyper::Response::builder()
+            .status(response.status)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(Full::new(Bytes::from(response.body.to_string())))
+            .expect("mock response is valid"))
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for MockHomegate {
+    async fn send(&self, request: Request) -> crate::Result<Response> {
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .unwrap_or_default();
+        let response = self.handle(request.method().as_str(), request.url().path(), body);
+
+        let response = http::Response::builder()
+            .status(response.status)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(response.body.to_string())
+            .expect("mock response is valid");
+        Ok(Response::from(response))
+    }
+}
+
+/// Handle to a [`MockHomegate`] served over HTTP by [`MockHomegate::start`].
+///
+/// The server is shut down when the handle is dropped.
+#[derive(Debug)]
+pub struct MockHomegateServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MockHomegateServer {
+    /// Returns the address the server is bound to.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the base URL of the server, suitable for
+    /// [`HomegateClientBuilder::backend_url`](crate::api::request::HomegateClientBuilder::backend_url).
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for MockHomegateServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Returns `true` if a listing satisfies every filter of the query.
+fn matches_query(query: &Query, listing: &Listing) -> bool {
+    let unbounded = FromTo::default();
+    let price_range = match query.offer_type {
+        OfferType::RENT => &query.monthly_rent,
+        OfferType::BUY => &query.purchase_price,
+        OfferType::Unknown(_) => &unbounded,
+    };
+    let price = listing
+        .prices
+        .for_offer_type(&listing.offer_type)
+        .and_then(|p| p.amount());
+
+    listing.offer_type == query.offer_type
+        && matches_location(&query.location, &listing.address)
+        && matches_categories(query, listing)
+        && in_range(price_range, price)
+        && in_range(&query.living_space, listing.characteristics.living_space)
+        && in_float_range(
+            &query.number_of_rooms,
+            listing.characteristics.number_of_rooms,
+        )
+}
+
+fn matches_location(location: &SearchLocation, address: &Address) -> bool {
+    match location {
+        SearchLocation::Radius(center) => {
+            distance(location, address).is_some_and(|d| d <= f64::from(center.radius))
+        }
+        SearchLocation::GeoTags { geo_tags } => {
+            let tags = address_geo_tags(address);
+            geo_tags.iter().any(|tag| tags.contains(tag))
+        }
+    }
+}
+
+/// A listing matches if one of its categories is requested and none is excluded.
+fn matches_categories(query: &Query, listing: &Listing) -> bool {
+    let names: Vec<String> = listing.categories.iter().map(|c| c.to_string()).collect();
+    names.iter().any(|n| query.categories.contains(n))
+        && !names.iter().any(|n| query.exclude_categories.contains(n))
+}
+
+fn in_range(range: &FromTo, value: Option<u32>) -> bool {
+    if range.from.is_none() && range.to.is_none() {
+        return true;
+    }
+    value.is_some_and(|v| {
+        range.from.is_none_or(|from| v >= from) && range.to.is_none_or(|to| v <= to)
+    })
+}
+
+fn in_float_range(range: &FromToFloat, value: Option<f32>) -> bool {
+    if range.from.is_none() && range.to.is_none() {
+        return true;
+    }
+    value.is_some_and(|v| {
+        range.from.is_none_or(|from| v >= from) && range.to.is_none_or(|to| v <= to)
+    })
+}
+
+/// Approximates the geo tags of an address: its postal code and its city.
+///
+/// The real API resolves tags through its own location database; this covers the
+/// `geo-zipcode-*` and `geo-city-*` tags returned by the geo lookup.
+fn address_geo_tags(address: &Address) -> Vec<String> {
+    let mut tags = vec![format!("geo-zipcode-{}", address.postal_code)];
+    if let Some(locality) = &address.locality {
+        tags.push(format!("geo-city-{}", slug(locality)));
+    }
+    tags
+}
+
+fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ä' | 'à' | 'á' | 'â' => 'a',
+            'ö' | 'ò' | 'ó' | 'ô' => 'o',
+            'ü' | 'ù' | 'ú' | 'û' => 'u',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'ï' | 'î' => 'i',
+            'ç' => 'c',
+            c if c.is_ascii_alphanumeric() => c,
+            _ => '-',
+        })
+        .collect()
+}
+
+/// Distance in meters from the search center, for radius searches.
+fn distance(location: &SearchLocation, address: &Address) -> Option<f64> {
+    let center = location.as_radius()?;
+    let coords = &address.geo_coordinates;
+    Some(haversine(
+        f64::from(center.latitude),
+        f64::from(center.longitude),
+        coords.latitude,
+        coords.longitude,
+    ))
+}
+
+/// Great-circle distance in meters between two points given in degrees.
+fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
+/// Returns the value a listing is sorted by, if the dataset supports it.
+///
+/// Listing tiers rank `PREMIUM` above `TOP` above `STANDARD`. Fixtures carry no
+/// publication date, so that sort keeps the dataset order.
+fn sort_key(sort_by: SortBy, real_estate: &RealEstate, distance: Option<f64>) -> Option<f64> {
+    let listing = &real_estate.listing;
+    match sort_by {
+        SortBy::Price => listing
+            .prices
+            .for_offer_type(&listing.offer_type)
+            .and_then(|p| p.amount())
+            .map(f64::from),
+        SortBy::LivingSpace => listing.characteristics.living_space.map(f64::from),
+        SortBy::NumberOfRooms => listing.characteristics.number_of_rooms.map(f64::from),
+        SortBy::Distance => distance,
+        SortBy::ListingType => match real_estate.listing_type()? {
+            ListingType::PREMIUM => Some(3.0),
+            ListingType::TOP => Some(2.0),
+            ListingType::STANDARD => Some(1.0),
+            ListingType::Unknown(_) => None,
+        },
+        SortBy::PublicationDate => None,
+    }
+}
+
+/// Orders two sort keys; listings without a value always come last.
+fn compare_keys(a: Option<f64>, b: Option<f64>, direction: SortDirection) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            match direction {
+                SortDirection::Asc => ordering,
+                SortDirection::Desc => ordering.reverse(),
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Starting index of the last page.
+fn max_from(total: usize, size: usize) -> usize {
+    if total == 0 || size == 0 {
+        0
+    } else {
+        (total - 1) / size * size
+    }
+}
@@ -44,6 +44,11 @@ pub fn load_result_2() -> String {
     load_fixture("result-2.json")
 }
 
+/// Loads the listing-detail-1.json fixture
+pub fn load_listing_detail_1() -> String {
+    load_fixture("listing-detail-1.json")
+}
+
 /// Loads a fixture and parses it as JSON
 ///
 /// # Arguments
@@ -4,6 +4,8 @@
 /// loading fixtures, and other test utilities.
 pub mod fixtures;
 
+use std::path::PathBuf;
+
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -16,6 +18,20 @@ pub async fn setup_mock_server() -> MockServer {
     MockServer::start().await
 }
 
+/// Returns an empty scratch directory unique to this test process
+///
+/// # Arguments
+///
+/// * `name` - Name of the directory, unique per test
+#[allow(dead_code)]
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("homegate-test-{}", std::process::id()))
+        .join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
 /// Creates a mock for a successful search request
 ///
 /// # Arguments
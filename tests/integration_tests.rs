@@ -6,7 +6,7 @@ mod common;
 
 #[cfg(test)]
 mod tests {
-    use crate::common::fixtures::{load_result_1, load_result_2};
+    use crate::common::fixtures::{load_listing_detail_1, load_result_1, load_result_2};
     use homegate::api::search::{default_search, Location};
     use homegate::models::paginated::{parse_search_result, Paginated};
     use homegate::models::realestate::RealEstate;
@@ -91,8 +91,8 @@ mod tests {
             assert!(!result.listing.address.postal_code.is_empty());
 
             // Verify characteristics
-            assert!(result.listing.characteristics.living_space > 0);
-            assert!(result.listing.characteristics.number_of_rooms > 0.0);
+            assert!(result.listing.characteristics.living_space.unwrap() > 0);
+            assert!(result.listing.characteristics.number_of_rooms.unwrap() > 0.0);
 
             // Verify localization
             assert!(!result.listing.localization.primary.is_empty());
@@ -109,12 +109,13 @@ mod tests {
         };
 
         let mut search = default_search();
-        search.query.location = location.clone();
+        search.query.location = location.clone().into();
 
         // Verify location is properly integrated
-        assert_eq!(search.query.location.latitude, 47.36667);
-        assert_eq!(search.query.location.longitude, 8.55);
-        assert_eq!(search.query.location.radius, 1000);
+        let radius = search.query.location.as_radius().unwrap();
+        assert_eq!(radius.latitude, 47.36667);
+        assert_eq!(radius.longitude, 8.55);
+        assert_eq!(radius.radius, 1000);
 
         // Verify it can be serialized
         let json = serde_json::to_string(&search).unwrap();
@@ -165,6 +166,1005 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_client_search_against_mock_backend() {
+        use crate::common::{mock_search_request, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+
+        let server = setup_mock_server().await;
+        mock_search_request(&server, &load_result_2()).await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+
+        let result = client.search(&location).await.unwrap();
+        assert!(result.total > 0);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_search_skips_malformed_listings() {
+        use crate::common::{mock_search_request, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::config::ParseMode;
+        use homegate::HomegateError;
+
+        let mut json: serde_json::Value = serde_json::from_str(&load_result_2()).unwrap();
+        let count = json["results"].as_array().unwrap().len();
+        json["results"][0]["listing"]["characteristics"]["numberOfRooms"] =
+            serde_json::json!("many");
+
+        let server = setup_mock_server().await;
+        mock_search_request(&server, &json.to_string()).await;
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+
+        let lenient = HomegateClient::builder()
+            .backend_url(server.uri())
+            .parse_mode(ParseMode::Lenient)
+            .build()
+            .unwrap();
+        let result = lenient.search(&location).await.unwrap();
+        assert_eq!(result.results.len(), count - 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(
+            result.skipped[0].path,
+            "results[0].listing.characteristics.numberOfRooms"
+        );
+
+        // Strict is the default
+        let strict = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            strict.search(&location).await,
+            Err(HomegateError::ParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_search_with_sends_query() {
+        use crate::common::setup_mock_server;
+        use homegate::api::request::HomegateClient;
+        use homegate::api::search::SearchQuery;
+        use homegate::models::listing::Category;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/search/listings"))
+            .and(body_partial_json(serde_json::json!({
+                "from": 20,
+                "size": 20,
+                "query": {
+                    "categories": ["STUDIO"],
+                    "monthlyRent": { "to": 1500 }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(load_result_2()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+        let query = SearchQuery::new(Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        })
+        .categories([Category::Studio])
+        .monthly_rent(None, Some(1500))
+        .page(2);
+
+        let result = client.search_with(&query).await.unwrap();
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_search_all_pages_until_max_from() {
+        use crate::common::{mock_search_request, setup_mock_server};
+        use futures::StreamExt;
+        use homegate::api::request::HomegateClient;
+        use homegate::api::search::{SearchAllOptions, SearchQuery};
+
+        // result-2.json reports total = 48 and maxFrom = 40 with 20 results per page
+        let server = setup_mock_server().await;
+        mock_search_request(&server, &load_result_2()).await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+        let query = SearchQuery::new(Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        });
+
+        let options = SearchAllOptions {
+            limit: None,
+            concurrency: 2,
+        };
+        let listings: Vec<_> = client.search_all(&query, options).collect().await;
+        assert_eq!(listings.len(), 60);
+        assert!(listings.iter().all(|l| l.is_ok()));
+
+        let requests = server.received_requests().await.unwrap();
+        let mut offsets: Vec<i64> = requests
+            .iter()
+            .map(|r| {
+                r.body_json::<serde_json::Value>().unwrap()["from"]
+                    .as_i64()
+                    .unwrap()
+            })
+            .collect();
+        offsets.sort();
+        assert_eq!(offsets, vec![0, 20, 40]);
+    }
+
+    #[tokio::test]
+    async fn test_client_search_all_respects_limit() {
+        use crate::common::{mock_search_request, setup_mock_server};
+        use futures::StreamExt;
+        use homegate::api::request::HomegateClient;
+        use homegate::api::search::{SearchAllOptions, SearchQuery};
+
+        let server = setup_mock_server().await;
+        mock_search_request(&server, &load_result_2()).await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+        let query = SearchQuery::new(Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        });
+
+        let options = SearchAllOptions {
+            limit: Some(25),
+            ..SearchAllOptions::default()
+        };
+        let listings: Vec<_> = client.search_all(&query, options).collect().await;
+        assert_eq!(listings.len(), 25);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_client_search_all_yields_error_for_failed_page() {
+        use crate::common::{mock_error_request, setup_mock_server};
+        use futures::StreamExt;
+        use homegate::api::request::HomegateClient;
+        use homegate::api::search::{SearchAllOptions, SearchQuery};
+
+        let server = setup_mock_server().await;
+        mock_error_request(&server, "POST", "/search/listings", 400).await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .max_retries(0)
+            .build()
+            .unwrap();
+        let query = SearchQuery::new(Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        });
+
+        let listings: Vec<_> = client
+            .search_all(&query, SearchAllOptions::default())
+            .collect()
+            .await;
+        assert_eq!(listings.len(), 1);
+        assert!(listings[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_geo_lookup_and_search_by_geo_tag() {
+        use crate::common::setup_mock_server;
+        use homegate::api::request::HomegateClient;
+        use homegate::api::search::SearchQuery;
+        use wiremock::matchers::{body_partial_json, method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .and(query_param("name", "Winterthur"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{
+                    "id": "geo-city-winterthur",
+                    "name": "Winterthur",
+                    "type": "city",
+                    "typeLabel": "City",
+                    "geoCoordinates": { "latitude": 47.49988, "longitude": 8.72410 }
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/search/listings"))
+            .and(body_partial_json(serde_json::json!({
+                "query": { "location": { "geoTags": ["geo-city-winterthur"] } }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(load_result_1()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+
+        let locations = client.geo_lookup("Winterthur").await.unwrap();
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].id(), "geo-city-winterthur");
+        assert_eq!(locations[0].name(), "Winterthur");
+        assert_eq!(locations[0].type_label(), "City");
+        assert_eq!(locations[0].geo_coordinates().unwrap().latitude, 47.49988);
+
+        let result = client
+            .search_with(&SearchQuery::new(&locations[0]))
+            .await
+            .unwrap();
+        assert_eq!(result.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_get_listing() {
+        use crate::common::{mock_get_request, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+
+        let server = setup_mock_server().await;
+        mock_get_request(&server, "/listings/3001439887", &load_listing_detail_1()).await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .build()
+            .unwrap();
+
+        let detail = client.get_listing("3001439887").await.unwrap();
+        assert_eq!(detail.id, "3001439887");
+        assert_eq!(
+            detail.available_from,
+            chrono::NaiveDate::from_ymd_opt(2024, 4, 1)
+        );
+        assert_eq!(detail.features, vec!["ELEVATOR", "CABLE_TV"]);
+        assert_eq!(detail.characteristics.year_built, Some(1890));
+        assert_eq!(
+            detail.lister.unwrap().phone.as_deref(),
+            Some("+41 44 262 36 00")
+        );
+
+        let en = detail.localization.en.as_ref().unwrap();
+        assert!(en.text.description.as_ref().unwrap().contains("old town"));
+    }
+
+    #[tokio::test]
+    async fn test_client_sends_configured_identity() {
+        use crate::common::{mock_get_request, setup_mock_server};
+        use homegate::api::app_id::AppIdentity;
+        use homegate::api::request::HomegateClient;
+
+        let server = setup_mock_server().await;
+        mock_get_request(&server, "/listings/3001439887", &load_listing_detail_1()).await;
+
+        let identity = AppIdentity {
+            platform: "iOS".to_string(),
+            version: "13.0.0".to_string(),
+            sdk_level: 17,
+            user_agent: "homegate.ch App iOS".to_string(),
+            username: "user".to_string(),
+            password: "pass".to_string(),
+            ..AppIdentity::android()
+        };
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .identity(identity.clone())
+            .build()
+            .unwrap();
+        client.get_listing("3001439887").await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let headers = &requests[0].headers;
+        assert_eq!(headers.get("user-agent").unwrap(), "homegate.ch App iOS");
+        assert_eq!(
+            headers.get("x-app-version").unwrap(),
+            "Homegate/13.0.0/12060003/iOS/17"
+        );
+        assert_eq!(headers.get("authorization").unwrap(), "Basic dXNlcjpwYXNz");
+
+        // The app ID is signed with the identity's user agent and version
+        let app_id = headers.get("x-app-id").unwrap().to_str().unwrap();
+        let now = chrono::Utc::now().naive_utc();
+        let candidates = [
+            identity.app_id(&(now - chrono::Duration::minutes(1))),
+            identity.app_id(&now),
+        ];
+        assert!(candidates.iter().any(|candidate| candidate == app_id));
+    }
+
+    #[tokio::test]
+    async fn test_client_app_id_follows_clock() {
+        use crate::common::{mock_get_request, setup_mock_server};
+        use chrono::TimeZone;
+        use homegate::api::app_id::calculate_app_id;
+        use homegate::api::request::HomegateClient;
+        use homegate::clock::{Clock, MockClock};
+
+        let server = setup_mock_server().await;
+        mock_get_request(&server, "/listings/3001439887", &load_listing_detail_1()).await;
+
+        let start = chrono::Utc
+            .with_ymd_and_hms(2022, 1, 25, 1, 30, 56)
+            .unwrap();
+        let clock = MockClock::new(start);
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .clock(clock.clone())
+            .build()
+            .unwrap();
+        assert_eq!(client.clock().now(), start);
+
+        // Within the same minute the ID is stable, it changes on rollover
+        client.get_listing("3001439887").await.unwrap();
+        clock.advance(chrono::Duration::seconds(3));
+        client.get_listing("3001439887").await.unwrap();
+        clock.advance(chrono::Duration::minutes(1));
+        client.get_listing("3001439887").await.unwrap();
+
+        let app_ids: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                request
+                    .headers
+                    .get("x-app-id")
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        let expected_first = calculate_app_id(&start.naive_utc());
+        let expected_last = calculate_app_id(&clock.now().naive_utc());
+        assert_eq!(
+            app_ids,
+            vec![expected_first.clone(), expected_first, expected_last]
+        );
+        assert_ne!(app_ids[1], app_ids[2]);
+    }
+
+    #[tokio::test]
+    async fn test_client_retry_after_date_uses_clock() {
+        use crate::common::setup_mock_server;
+        use homegate::api::request::HomegateClient;
+        use homegate::clock::MockClock;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"),
+            )
+            .mount(&server)
+            .await;
+
+        let clock = MockClock::new(
+            chrono::DateTime::parse_from_rfc3339("2015-10-21T07:26:30Z")
+                .unwrap()
+                .to_utc(),
+        );
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .max_retries(0)
+            .clock(clock.clone())
+            .build()
+            .unwrap();
+
+        let err = client.geo_lookup("Zurich").await.unwrap_err();
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(90)));
+
+        // Dates in the past mean no delay
+        clock.advance(chrono::Duration::minutes(5));
+        let err = client.geo_lookup("Zurich").await.unwrap_err();
+        assert_eq!(err.retry_after(), Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_client_cache_expires_with_clock() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::clock::MockClock;
+        use homegate::config::CacheConfig;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "results": [] })),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let clock = MockClock::default();
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .clock(clock.clone())
+            .cache(CacheConfig {
+                dir: scratch_dir("cache-clock"),
+                ttl: std::time::Duration::from_secs(60),
+                ..CacheConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        client.geo_lookup("Bern").await.unwrap();
+        clock.advance(chrono::Duration::seconds(30));
+        client.geo_lookup("Bern").await.unwrap();
+        clock.advance(chrono::Duration::seconds(31));
+        client.geo_lookup("Bern").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_get_listing_rejects_invalid_id() {
+        use homegate::api::request::HomegateClient;
+        use homegate::HomegateError;
+
+        let client = HomegateClient::new().unwrap();
+        assert!(matches!(
+            client.get_listing("../search").await,
+            Err(HomegateError::ValidationError(_))
+        ));
+        assert!(matches!(
+            client.get_listing("  ").await,
+            Err(HomegateError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_daily_budget_is_shared_by_clones() {
+        use crate::common::setup_mock_server;
+        use homegate::api::request::HomegateClient;
+        use homegate::config::RateLimitConfig;
+        use homegate::HomegateError;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/search/listings"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(load_result_2()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .rate_limit(RateLimitConfig {
+                daily_budget: Some(2),
+                ..RateLimitConfig::default()
+            })
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+        client.search(&location).await.unwrap();
+        clone.search(&location).await.unwrap();
+
+        let err = client.search(&location).await.unwrap_err();
+        assert!(
+            matches!(err, HomegateError::BudgetExhausted { budget: 2 }),
+            "{:?}",
+            err
+        );
+        assert!(!err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_client_cache_serves_repeated_searches() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::config::CacheConfig;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/search/listings"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(load_result_2()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let cache = CacheConfig {
+            dir: scratch_dir("cache-search"),
+            ..CacheConfig::default()
+        };
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(cache.clone())
+            .build()
+            .unwrap();
+
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+        let first = client.search(&location).await.unwrap();
+        let cached = client.search(&location).await.unwrap();
+        assert_eq!(first.total, cached.total);
+        assert_eq!(first.results.len(), cached.results.len());
+
+        // A new client sharing the directory also hits the cache
+        let other = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(cache.clone())
+            .build()
+            .unwrap();
+        other.search(&location).await.unwrap();
+
+        // Refreshing bypasses the cached entry
+        let refreshing = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(CacheConfig {
+                refresh: true,
+                ..cache
+            })
+            .build()
+            .unwrap();
+        refreshing.search(&location).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_cache_skips_errors_and_expired_entries() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::config::CacheConfig;
+        use homegate::HomegateError;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "results": [] })),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let dir = scratch_dir("cache-expired");
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .max_retries(0)
+            .cache(CacheConfig {
+                dir: dir.clone(),
+                ..CacheConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        let err = client.geo_lookup("Zurich").await.unwrap_err();
+        assert!(matches!(err, HomegateError::ServerError { .. }));
+        assert!(!dir.exists() || std::fs::read_dir(&dir).unwrap().next().is_none());
+
+        assert!(client.geo_lookup("Zurich").await.unwrap().is_empty());
+
+        // Expire the stored entry, it must not be served
+        let entries: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(entries.len(), 1);
+        std::fs::write(
+            &entries[0],
+            r#"{"stored_at":0,"status":200,"content_type":null,"body":"{\"results\":[{}]}"}"#,
+        )
+        .unwrap();
+
+        let locations = client.geo_lookup("Zurich").await.unwrap();
+        assert!(locations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_cache_keeps_binary_bodies_and_separates_identities() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::app_id::AppIdentity;
+        use homegate::api::request::HomegateClient;
+        use homegate::config::CacheConfig;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let body = vec![0xff, 0xfe, 0x00, 0x80];
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/binary"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let cache = CacheConfig {
+            dir: scratch_dir("cache-binary"),
+            ..CacheConfig::default()
+        };
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(cache.clone())
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let url = client.endpoint("/binary").unwrap();
+            let response = client.get_url(url).await.unwrap();
+            assert_eq!(response.bytes().await.unwrap().to_vec(), body);
+        }
+
+        // A client with another identity does not share the cached entry
+        let other = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(cache)
+            .identity(AppIdentity {
+                username: "other".to_string(),
+                ..AppIdentity::android()
+            })
+            .build()
+            .unwrap();
+        let url = other.endpoint("/binary").unwrap();
+        other.get_url(url).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_cache_stays_under_size_cap_with_concurrent_writes() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::config::CacheConfig;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/geo/locations"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "results": [] })),
+            )
+            .mount(&server)
+            .await;
+
+        let dir = scratch_dir("cache-size-cap");
+        let client = HomegateClient::builder()
+            .backend_url(server.uri())
+            .cache(CacheConfig {
+                dir: dir.clone(),
+                max_size_bytes: 300,
+                ..CacheConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        // Concurrent lookups of the same and of different names
+        let names = [
+            "Zurich", "Zurich", "Zurich", "Bern", "Basel", "Luzern", "Genf",
+        ];
+        let lookups = names.iter().map(|name| client.geo_lookup(name));
+        for result in futures::future::join_all(lookups).await {
+            assert!(result.unwrap().is_empty());
+        }
+
+        let files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert!(!files.is_empty());
+        assert!(files
+            .iter()
+            .all(|path| path.extension().is_some_and(|ext| ext == "json")));
+        let total: u64 = files
+            .iter()
+            .map(|path| std::fs::metadata(path).unwrap().len())
+            .sum();
+        assert!(total <= 300, "cache holds {} bytes", total);
+    }
+
+    #[tokio::test]
+    async fn test_client_cassette_records_and_replays() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use homegate::api::request::HomegateClient;
+        use homegate::config::{CassetteConfig, CassetteMode};
+        use homegate::HomegateError;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let cassette = scratch_dir("cassette").join("search.json");
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+
+        let recorded = {
+            let server = setup_mock_server().await;
+            Mock::given(method("POST"))
+                .and(path("/search/listings"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(load_result_2()))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let client = HomegateClient::builder()
+                .backend_url(server.uri())
+                .cassette(CassetteConfig {
+                    path: cassette.clone(),
+                    mode: CassetteMode::Record,
+                })
+                .build()
+                .unwrap();
+            client.search(&location).await.unwrap()
+        };
+
+        let file: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&cassette).unwrap()).unwrap();
+        let interaction = &file["interactions"][0];
+        assert_eq!(interaction["request"]["method"], "POST");
+        assert_eq!(interaction["request"]["path"], "/search/listings");
+        assert_eq!(interaction["response"]["status"], 200);
+
+        // The server is gone, replaying must not touch the network
+        let client = HomegateClient::builder()
+            .backend_url("http://127.0.0.1:9")
+            .max_retries(0)
+            .cassette(CassetteConfig {
+                path: cassette,
+                mode: CassetteMode::Replay,
+            })
+            .build()
+            .unwrap();
+        let replayed = client.search(&location).await.unwrap();
+        assert_eq!(replayed.total, recorded.total);
+        assert_eq!(replayed.results.len(), recorded.results.len());
+        assert_eq!(
+            replayed.results[0].listing.id,
+            recorded.results[0].listing.id
+        );
+
+        let other = Location {
+            radius: 2000,
+            ..location
+        };
+        let err = client.search(&other).await.unwrap_err();
+        assert!(matches!(err, HomegateError::Cassette(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_client_cassette_matches_normalized_body() {
+        use crate::common::scratch_dir;
+        use homegate::api::request::HomegateClient;
+        use homegate::config::{CassetteConfig, CassetteMode};
+
+        // The recorded body has its keys in a different order than the client sends
+        let mut request = serde_json::to_value(default_search()).unwrap();
+        request["query"]["location"] =
+            serde_json::json!({ "radius": 1000, "longitude": 8.55, "latitude": 47.36667 });
+        let cassette = serde_json::json!({
+            "interactions": [{
+                "request": { "method": "POST", "path": "/search/listings", "body": request },
+                "response": {
+                    "status": 200,
+                    "headers": { "content-type": "application/json" },
+                    "body": load_result_2()
+                }
+            }]
+        });
+        let path = scratch_dir("cassette-normalized").join("search.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, cassette.to_string()).unwrap();
+
+        let client = HomegateClient::builder()
+            .backend_url("http://127.0.0.1:9")
+            .cassette(CassetteConfig {
+                path,
+                mode: CassetteMode::Replay,
+            })
+            .build()
+            .unwrap();
+        let result = client
+            .search(&Location {
+                latitude: 47.36667,
+                longitude: 8.55,
+                radius: 1000,
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.results.len(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_client_search_maps_http_status_errors() {
+        use crate::common::setup_mock_server;
+        use homegate::api::request::HomegateClient;
+        use homegate::HomegateError;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let location = Location {
+            latitude: 47.36667,
+            longitude: 8.55,
+            radius: 1000,
+        };
+
+        let cases = [
+            (
+                ResponseTemplate::new(401).set_body_string("bad credentials"),
+                "unauthorized",
+            ),
+            (
+                ResponseTemplate::new(429).insert_header("Retry-After", "120"),
+                "rate limited",
+            ),
+            (ResponseTemplate::new(503), "server error"),
+            (ResponseTemplate::new(404), "unexpected"),
+        ];
+
+        for (response, case) in cases {
+            let server = setup_mock_server().await;
+            Mock::given(method("POST"))
+                .and(path("/search/listings"))
+                .respond_with(response)
+                .mount(&server)
+                .await;
+
+            let client = HomegateClient::builder()
+                .backend_url(server.uri())
+                .max_retries(0)
+                .build()
+                .unwrap();
+            let err = client.search(&location).await.unwrap_err();
+
+            match case {
+                "unauthorized" => assert!(matches!(
+                    err,
+                    HomegateError::Unauthorized { status: 401, ref body } if body == "bad credentials"
+                )),
+                "rate limited" => {
+                    assert!(err.is_retryable());
+                    assert_eq!(err.retry_after(), Some(Duration::from_secs(120)));
+                }
+                "server error" => assert!(matches!(
+                    err,
+                    HomegateError::ServerError { status: 503, .. }
+                )),
+                _ => assert!(matches!(
+                    err,
+                    HomegateError::UnexpectedStatus { status: 404, .. }
+                )),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_media_archiver_writes_files_and_manifest() {
+        use crate::common::{scratch_dir, setup_mock_server};
+        use chrono::{TimeZone, Utc};
+        use homegate::api::media::{MediaArchiver, MediaManifest, LISTING_FILE, MANIFEST_FILE};
+        use homegate::clock::MockClock;
+        use homegate::models::listing::AttachmentKind;
+        use homegate::models::listing_detail::ListingDetail;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = setup_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/listings/hgonif/3001439887/image/83545b14340838b13bfa33688ce213ed.jpeg",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"jpeg".to_vec()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/listings/hgonif/3001439887/document/grundriss.pdf"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let mut json: serde_json::Value = serde_json::from_str(
+            &load_listing_detail_1().replace("https://media2.homegate.ch", &server.uri()),
+        )
+        .unwrap();
+        json["localization"]["de"]["attachments"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({
+                "type": "VIRTUAL_TOUR",
+                "url": "https://tour.example.com/3001439887",
+                "file": ""
+            }));
+        let detail: ListingDetail = serde_json::from_value(json).unwrap();
+
+        let archived_at = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+        let dir = scratch_dir("media-archive");
+        let manifest = MediaArchiver::new()
+            .clock(MockClock::new(archived_at))
+            .archive(&detail, &dir)
+            .await
+            .unwrap();
+
+        assert_eq!(manifest.listing_id, "3001439887");
+        assert_eq!(manifest.archived_at, archived_at);
+        assert_eq!(manifest.items.len(), 3);
+
+        let image = &manifest.items[0];
+        assert_eq!(image.kind, AttachmentKind::Image);
+        assert_eq!(image.file.as_deref(), Some("01-image_1_e25eb25078.jpeg"));
+        assert_eq!(image.size, Some(4));
+        assert_eq!(
+            std::fs::read(dir.join("01-image_1_e25eb25078.jpeg")).unwrap(),
+            b"jpeg"
+        );
+
+        // A failed download is recorded instead of aborting the archive
+        let floor_plan = &manifest.items[1];
+        assert_eq!(floor_plan.kind, AttachmentKind::FloorPlan);
+        assert!(floor_plan.file.is_none());
+        assert!(floor_plan.error.as_ref().unwrap().contains("404"));
+        assert_eq!(manifest.failed().count(), 1);
+
+        // Virtual tours are only recorded
+        let tour = &manifest.items[2];
+        assert!(tour.file.is_none() && tour.error.is_none());
+
+        let written: MediaManifest =
+            serde_json::from_str(&std::fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap())
+                .unwrap();
+        assert_eq!(written, manifest);
+        let listing: ListingDetail =
+            serde_json::from_str(&std::fs::read_to_string(dir.join(LISTING_FILE)).unwrap())
+                .unwrap();
+        assert_eq!(listing.id, detail.id);
+    }
+
     #[tokio::test]
     #[ignore] // Ignored because it requires network access
     async fn test_live_api_integration() {
@@ -125,3 +125,190 @@ fn test_app_version_consistency() {
         "app_version should return consistent results"
     );
 }
+
+#[tokio::test]
+async fn test_app_id_middleware_uses_injected_clock() {
+    use homegate::api::middleware::AppIdMiddleware;
+    use wiremock::matchers::{header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let dt = create_datetime(2022, 1, 25, 1, 30, 56);
+    let expected = homegate::api::app_id::calculate_app_id(&dt);
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("x-app-id", expected.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+        .with(AppIdMiddleware::with_clock(move || dt))
+        .build();
+
+    let response = client.get(mock_server.uri()).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_app_id_middleware_recomputes_on_retry() {
+    use homegate::api::middleware::AppIdMiddleware;
+    use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    // Each call to the clock advances time by one minute
+    let start = create_datetime(2023, 6, 15, 10, 0, 0);
+    let minutes = Arc::new(AtomicI64::new(0));
+    let clock_minutes = minutes.clone();
+    let clock =
+        move || start + chrono::Duration::minutes(clock_minutes.fetch_add(1, Ordering::SeqCst));
+
+    let retry_policy = ExponentialBackoff::builder()
+        .retry_bounds(Duration::from_millis(1), Duration::from_millis(10))
+        .build_with_max_retries(1);
+    let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+        .with(RetryTransientMiddleware::new_with_policy(retry_policy))
+        .with(AppIdMiddleware::with_clock(clock))
+        .build();
+
+    let response = client.get(mock_server.uri()).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].headers.get("x-app-id").unwrap(),
+        homegate::api::app_id::calculate_app_id(&start).as_str()
+    );
+    assert_eq!(
+        requests[1].headers.get("x-app-id").unwrap(),
+        homegate::api::app_id::calculate_app_id(&(start + chrono::Duration::minutes(1))).as_str()
+    );
+}
+
+#[test]
+fn test_android_identity_matches_free_functions() {
+    use homegate::api::app_id::AppIdentity;
+
+    let identity = AppIdentity::android();
+    let dt = create_datetime(2023, 6, 15, 10, 0, 0);
+
+    assert_eq!(identity, AppIdentity::default());
+    assert_eq!(identity.app_version(), homegate::api::app_id::app_version());
+    assert_eq!(
+        identity.app_id(&dt),
+        homegate::api::app_id::calculate_app_id(&dt)
+    );
+}
+
+#[test]
+fn test_custom_identity_changes_app_id() {
+    use homegate::api::app_id::AppIdentity;
+
+    let dt = create_datetime(2023, 6, 15, 10, 0, 0);
+    let android = AppIdentity::android();
+    let updated = AppIdentity {
+        version: "12.7.0".to_string(),
+        build: "12070001".to_string(),
+        ..AppIdentity::android()
+    };
+    let rekeyed = AppIdentity {
+        secret: b"another-secret".to_vec(),
+        ..AppIdentity::android()
+    };
+
+    assert_eq!(updated.app_version(), "Homegate/12.7.0/12070001/Android/30");
+    assert_ne!(updated.app_id(&dt), android.app_id(&dt));
+    assert_ne!(rekeyed.app_id(&dt), android.app_id(&dt));
+    assert!(rekeyed.app_id(&dt).parse::<i64>().is_ok());
+}
+
+#[test]
+fn test_identity_authorization() {
+    use homegate::api::app_id::AppIdentity;
+
+    let identity = AppIdentity {
+        username: "user".to_string(),
+        password: "pass".to_string(),
+        ..AppIdentity::android()
+    };
+    assert_eq!(identity.authorization(), "Basic dXNlcjpwYXNz");
+}
+
+#[test]
+fn test_identity_preset_from_str() {
+    use homegate::api::app_id::AppIdentity;
+
+    assert_eq!(
+        "android".parse::<AppIdentity>().unwrap(),
+        AppIdentity::android()
+    );
+    assert_eq!(
+        " Android ".parse::<AppIdentity>().unwrap(),
+        AppIdentity::android()
+    );
+    assert!("ios".parse::<AppIdentity>().is_err());
+    assert!("windows".parse::<AppIdentity>().is_err());
+}
+
+#[test]
+fn test_identity_debug_hides_secrets() {
+    use homegate::api::app_id::AppIdentity;
+
+    let identity = AppIdentity {
+        secret: b"top-secret".to_vec(),
+        password: "hunter2".to_string(),
+        ..AppIdentity::android()
+    };
+    let debug = format!("{:?}", identity);
+    assert!(debug.contains("Android"));
+    assert!(!debug.contains("hunter2"));
+    assert!(!debug.contains("top-secret"));
+}
+
+#[test]
+fn test_identity_from_env() {
+    use homegate::api::app_id::{
+        AppIdentity, APP_PLATFORM_ENV, APP_PRESET_ENV, APP_SDK_LEVEL_ENV, APP_SECRET_ENV,
+        APP_VERSION_ENV,
+    };
+
+    // The only test in this binary touching these variables
+    std::env::set_var(APP_PRESET_ENV, "android");
+    std::env::set_var(APP_PLATFORM_ENV, "iOS");
+    std::env::set_var(APP_VERSION_ENV, "13.0.0");
+    std::env::set_var(APP_SECRET_ENV, "s3cr3t");
+    std::env::set_var(APP_SDK_LEVEL_ENV, "18");
+    let identity = AppIdentity::from_env().unwrap();
+    assert_eq!(identity.app_version(), "Homegate/13.0.0/12060003/iOS/18");
+    assert_eq!(identity.secret, b"s3cr3t");
+
+    std::env::set_var(APP_SDK_LEVEL_ENV, "latest");
+    assert!(AppIdentity::from_env().is_err());
+
+    std::env::set_var(APP_PRESET_ENV, "windows");
+    std::env::remove_var(APP_SDK_LEVEL_ENV);
+    assert!(AppIdentity::from_env().is_err());
+
+    std::env::remove_var(APP_PRESET_ENV);
+    std::env::remove_var(APP_PLATFORM_ENV);
+    std::env::remove_var(APP_VERSION_ENV);
+    std::env::remove_var(APP_SECRET_ENV);
+    assert_eq!(AppIdentity::from_env().unwrap(), AppIdentity::android());
+}
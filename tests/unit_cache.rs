@@ -0,0 +1,109 @@
+/// Unit tests for cache module
+///
+/// Tests cache key computation and clearing of the cache directory
+use homegate::api::cache::ResponseCache;
+use homegate::config::CacheConfig;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+const URL: &str = "https://api.homegate.ch/search/listings";
+
+#[test]
+fn test_cache_key_ignores_json_key_order() {
+    let a = ResponseCache::cache_key(
+        "POST",
+        URL,
+        &HeaderMap::new(),
+        Some(br#"{"a":1,"b":{"c":[1,2],"d":null}}"#),
+    );
+    let b = ResponseCache::cache_key(
+        "POST",
+        URL,
+        &HeaderMap::new(),
+        Some(br#"{ "b": { "d": null, "c": [1, 2] }, "a": 1 }"#),
+    );
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 64);
+}
+
+#[test]
+fn test_cache_key_distinguishes_requests() {
+    let body = br#"{"from":0}"#;
+    let base = ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(body));
+
+    assert_ne!(
+        base,
+        ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(br#"{"from":20}"#))
+    );
+    assert_ne!(
+        base,
+        ResponseCache::cache_key("GET", URL, &HeaderMap::new(), Some(body))
+    );
+    assert_ne!(
+        base,
+        ResponseCache::cache_key(
+            "POST",
+            "https://api.homegate.ch/other",
+            &HeaderMap::new(),
+            Some(body)
+        )
+    );
+    assert_ne!(
+        base,
+        ResponseCache::cache_key("POST", URL, &HeaderMap::new(), None)
+    );
+    // Array order is significant
+    assert_ne!(
+        ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(b"[1,2]")),
+        ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(b"[2,1]"))
+    );
+}
+
+#[test]
+fn test_cache_key_includes_headers_except_app_id() {
+    let body = Some(&br#"{"from":0}"#[..]);
+    let mut android = HeaderMap::new();
+    android.insert(
+        "user-agent",
+        HeaderValue::from_static("homegate.ch App Android"),
+    );
+    let base = ResponseCache::cache_key("POST", URL, &android, body);
+
+    let mut other = HeaderMap::new();
+    other.insert(
+        "user-agent",
+        HeaderValue::from_static("homegate.ch App Other"),
+    );
+    assert_ne!(base, ResponseCache::cache_key("POST", URL, &other, body));
+
+    let mut with_app_id = android.clone();
+    with_app_id.insert(
+        "x-app-id",
+        HeaderValue::from_static("changes-every-request"),
+    );
+    assert_eq!(
+        base,
+        ResponseCache::cache_key("POST", URL, &with_app_id, body)
+    );
+}
+
+#[test]
+fn test_cache_key_non_json_body() {
+    let a = ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(b"not json"));
+    let b = ResponseCache::cache_key("POST", URL, &HeaderMap::new(), Some(b"not  json"));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_clear_missing_directory() {
+    let cache = ResponseCache::new(CacheConfig {
+        dir: std::env::temp_dir().join("homegate-test-missing-cache-dir"),
+        ..CacheConfig::default()
+    });
+    assert!(cache.clear().is_ok());
+}
+
+#[test]
+fn test_default_cache_dir_ends_with_crate_name() {
+    let dir = homegate::config::default_cache_dir();
+    assert!(dir.ends_with("homegate"));
+}
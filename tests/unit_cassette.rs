@@ -0,0 +1,50 @@
+/// Unit tests for cassette module
+///
+/// Tests cassette mode parsing and loading of cassette files
+use homegate::api::cassette::{Cassette, CassetteFile};
+use homegate::config::{CassetteConfig, CassetteMode};
+use homegate::HomegateError;
+
+#[test]
+fn test_cassette_mode_from_str() {
+    assert_eq!("record".parse::<CassetteMode>(), Ok(CassetteMode::Record));
+    assert_eq!(" REPLAY ".parse::<CassetteMode>(), Ok(CassetteMode::Replay));
+    assert!("rewind".parse::<CassetteMode>().is_err());
+    assert_eq!(CassetteMode::default(), CassetteMode::Replay);
+}
+
+#[test]
+fn test_replay_missing_cassette() {
+    let err = Cassette::new(CassetteConfig {
+        path: "/nonexistent/cassette.json".into(),
+        mode: CassetteMode::Replay,
+    })
+    .unwrap_err();
+    assert!(matches!(err, HomegateError::Cassette(_)));
+}
+
+#[test]
+fn test_record_does_not_require_file() {
+    let cassette = Cassette::new(CassetteConfig {
+        path: "/nonexistent/cassette.json".into(),
+        mode: CassetteMode::Record,
+    })
+    .unwrap();
+    assert!(cassette.interactions().is_empty());
+}
+
+#[test]
+fn test_cassette_file_format() {
+    let json = r#"{
+        "interactions": [{
+            "request": { "method": "GET", "path": "/geo/locations?name=Bern&size=10" },
+            "response": { "status": 200, "body": "{\"results\":[]}" }
+        }]
+    }"#;
+    let file: CassetteFile = serde_json::from_str(json).unwrap();
+
+    let interaction = &file.interactions[0];
+    assert_eq!(interaction.request.body, None);
+    assert!(interaction.response.headers.is_empty());
+    assert_eq!(interaction.response.status, 200);
+}
@@ -0,0 +1,50 @@
+/// Unit tests for clock module
+///
+/// Tests the system and mock clocks
+use chrono::{Duration, TimeZone, Utc};
+use homegate::clock::{Clock, MockClock, SystemClock};
+
+#[test]
+fn test_system_clock_returns_current_time() {
+    let before = Utc::now();
+    let now = SystemClock.now();
+    let after = Utc::now();
+
+    assert!(before <= now && now <= after);
+}
+
+#[test]
+fn test_mock_clock_is_frozen() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(start);
+
+    assert_eq!(clock.now(), start);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert_eq!(clock.now(), start);
+}
+
+#[test]
+fn test_mock_clock_set_and_advance() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(start);
+
+    clock.advance(Duration::seconds(90));
+    assert_eq!(clock.now(), start + Duration::seconds(90));
+
+    clock.advance(Duration::seconds(-30));
+    assert_eq!(clock.now(), start + Duration::seconds(60));
+
+    let later = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    clock.set(later);
+    assert_eq!(clock.now(), later);
+}
+
+#[test]
+fn test_mock_clock_clones_share_time() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(start);
+    let shared: Box<dyn Clock> = Box::new(clock.clone());
+
+    clock.advance(Duration::minutes(5));
+    assert_eq!(shared.now(), start + Duration::minutes(5));
+}
@@ -0,0 +1,94 @@
+/// Unit tests for error module
+///
+/// Tests HTTP status classification, retryability and error formatting
+use homegate::HomegateError;
+use reqwest::StatusCode;
+use std::time::Duration;
+
+#[test]
+fn test_from_status_unauthorized() {
+    for status in [StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN] {
+        let err = HomegateError::from_status(status, None, "denied");
+        assert!(matches!(err, HomegateError::Unauthorized { .. }));
+        assert_eq!(err.status(), Some(status.as_u16()));
+        assert!(!err.is_retryable());
+    }
+}
+
+#[test]
+fn test_from_status_rate_limited() {
+    let err = HomegateError::from_status(
+        StatusCode::TOO_MANY_REQUESTS,
+        Some(Duration::from_secs(30)),
+        "slow down",
+    );
+
+    assert!(matches!(err, HomegateError::RateLimited { .. }));
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+    assert_eq!(err.status(), Some(429));
+    assert_eq!(err.to_string(), "Rate limited (retry after 30s): slow down");
+}
+
+#[test]
+fn test_from_status_server_error() {
+    let err = HomegateError::from_status(StatusCode::BAD_GATEWAY, None, "");
+    assert!(matches!(
+        err,
+        HomegateError::ServerError { status: 502, .. }
+    ));
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), None);
+}
+
+#[test]
+fn test_from_status_unexpected() {
+    let err = HomegateError::from_status(StatusCode::NOT_FOUND, None, "not found");
+    assert!(matches!(
+        err,
+        HomegateError::UnexpectedStatus { status: 404, .. }
+    ));
+    assert!(!err.is_retryable());
+    assert_eq!(err.to_string(), "Unexpected status (404): not found");
+}
+
+#[test]
+fn test_from_status_truncates_body() {
+    let body = "é".repeat(2000);
+    let err = HomegateError::from_status(StatusCode::INTERNAL_SERVER_ERROR, None, &body);
+
+    match err {
+        HomegateError::ServerError { body, .. } => {
+            assert_eq!(body.chars().count(), 513);
+            assert!(body.ends_with('…'));
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_validation_error_is_not_retryable() {
+    let err = HomegateError::ValidationError("bad".to_string());
+    assert!(!err.is_retryable());
+    assert_eq!(err.status(), None);
+}
+
+#[tokio::test]
+async fn test_connection_failure_is_retryable() {
+    use homegate::api::request::HomegateClient;
+
+    // Reserve a free port, then close it so that connections are refused
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let client = HomegateClient::builder()
+        .backend_url(format!("http://{}", addr))
+        .max_retries(0)
+        .build()
+        .unwrap();
+    let err = client.geo_lookup("Bern").await.unwrap_err();
+
+    assert!(matches!(err, HomegateError::Request(ref e) if e.is_connect()));
+    assert!(err.is_retryable());
+}
@@ -0,0 +1,224 @@
+/// Unit tests for testing module
+///
+/// Tests the mock Homegate API against the search result fixtures
+use homegate::api::search::{Location, SearchQuery, SortBy, SortDirection};
+use homegate::models::listing::Category;
+use homegate::models::paginated::Paginated;
+use homegate::models::realestate::{OfferType, RealEstate};
+use homegate::testing::MockHomegate;
+use homegate::HomegateError;
+
+fn mock() -> MockHomegate {
+    MockHomegate::from_dir("resources/test").expect("Failed to load fixtures")
+}
+
+fn zurich(radius: u32) -> Location {
+    Location {
+        latitude: 47.36667,
+        longitude: 8.55,
+        radius,
+    }
+}
+
+async fn search(query: SearchQuery) -> Paginated<RealEstate> {
+    mock().client().unwrap().search_with(&query).await.unwrap()
+}
+
+#[test]
+fn test_from_dir_deduplicates_listings() {
+    // result-1.json repeats a listing of result-2.json
+    assert_eq!(mock().len(), 20);
+}
+
+#[test]
+fn test_from_fixtures_rejects_invalid_file() {
+    assert!(MockHomegate::from_fixtures(["resources/test/request-1.json"]).is_err());
+    assert!(MockHomegate::from_fixtures(["resources/test/missing.json"]).is_err());
+}
+
+#[tokio::test]
+async fn test_radius_filter() {
+    let result = search(SearchQuery::new(zurich(1000)).page_size(100)).await;
+    assert_eq!(result.total, 9);
+    assert_eq!(result.results.len(), 9);
+
+    let result = search(SearchQuery::new(zurich(100))).await;
+    assert_eq!(result.total, 0);
+    assert!(result.results.is_empty());
+}
+
+#[tokio::test]
+async fn test_range_filters() {
+    let result = search(
+        SearchQuery::new(zurich(5000))
+            .monthly_rent(Some(3000), Some(4000))
+            .number_of_rooms(Some(3.0), None)
+            .living_space(None, Some(100))
+            .page_size(100),
+    )
+    .await;
+
+    assert!(result.total > 0);
+    for real_estate in &result.results {
+        let listing = &real_estate.listing;
+        let rent = listing.prices.rent.as_ref().unwrap().amount().unwrap();
+        assert!((3000..=4000).contains(&rent));
+        assert!(listing.characteristics.number_of_rooms.unwrap() >= 3.0);
+        assert!(listing.characteristics.living_space.unwrap() <= 100);
+    }
+}
+
+#[tokio::test]
+async fn test_offer_type_filter() {
+    let result = search(SearchQuery::new(zurich(5000)).offer_type(OfferType::BUY)).await;
+    assert_eq!(result.total, 0);
+}
+
+#[tokio::test]
+async fn test_category_filters() {
+    let result = search(
+        SearchQuery::new(zurich(5000))
+            .categories(vec![Category::Duplex, Category::RoofFlat])
+            .page_size(100),
+    )
+    .await;
+    assert_eq!(result.total, 2);
+
+    let result = search(
+        SearchQuery::new(zurich(5000))
+            .categories(vec![Category::Apartment])
+            .exclude_categories(vec![Category::Duplex])
+            .page_size(100),
+    )
+    .await;
+    assert_eq!(result.total, 19);
+}
+
+#[tokio::test]
+async fn test_paging() {
+    let first = search(SearchQuery::new(zurich(5000)).page_size(6)).await;
+    assert_eq!(first.total, 20);
+    assert_eq!(first.from, 0);
+    assert_eq!(first.size, 6);
+    assert_eq!(first.max_from, 18);
+
+    let last = search(SearchQuery::new(zurich(5000)).page(4).page_size(6)).await;
+    assert_eq!(last.from, 18);
+    assert_eq!(last.results.len(), 2);
+    assert!(first
+        .results
+        .iter()
+        .all(|a| last.results.iter().all(|b| a.id != b.id)));
+}
+
+#[tokio::test]
+async fn test_sort_by_price() {
+    let result = search(
+        SearchQuery::new(zurich(5000))
+            .sort(SortBy::Price, SortDirection::Asc)
+            .page_size(100),
+    )
+    .await;
+
+    let prices: Vec<u32> = result
+        .results
+        .iter()
+        .map(|r| r.listing.prices.rent.as_ref().unwrap().amount().unwrap())
+        .collect();
+    assert!(prices.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[tokio::test]
+async fn test_sort_by_listing_type() {
+    use homegate::models::realestate::ListingType;
+
+    let tiers = |result: &Paginated<RealEstate>| -> Vec<ListingType> {
+        result
+            .results
+            .iter()
+            .map(|r| r.listing_type().unwrap().clone())
+            .collect()
+    };
+
+    let query = SearchQuery::new(zurich(5000)).page_size(100);
+    let asc = search(query.clone().sort(SortBy::ListingType, SortDirection::Asc)).await;
+    let asc = tiers(&asc);
+    assert_eq!(asc.first(), Some(&ListingType::STANDARD));
+    assert_eq!(asc.last(), Some(&ListingType::TOP));
+
+    let desc = search(query.sort(SortBy::ListingType, SortDirection::Desc)).await;
+    let desc = tiers(&desc);
+    assert_eq!(desc.iter().filter(|t| **t == ListingType::TOP).count(), 6);
+    assert!(desc[..6].iter().all(|t| *t == ListingType::TOP));
+}
+
+#[tokio::test]
+async fn test_sort_by_distance_desc() {
+    let result = search(
+        SearchQuery::new(zurich(1000))
+            .sort(SortBy::Distance, SortDirection::Desc)
+            .page_size(100),
+    )
+    .await;
+
+    // The closest listings (350m and 354m) come last
+    let ids: Vec<&str> = result.results.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids[ids.len() - 2..], ["3001560635", "3001625406"]);
+}
+
+#[tokio::test]
+async fn test_geo_tag_search() {
+    use homegate::api::search::SearchLocation;
+
+    let by_zip = SearchLocation::GeoTags {
+        geo_tags: vec!["geo-zipcode-8008".to_string()],
+    };
+    let result = search(SearchQuery::new(by_zip)).await;
+    assert_eq!(result.total, 3);
+
+    let by_city = SearchLocation::GeoTags {
+        geo_tags: vec!["geo-city-zurich".to_string()],
+    };
+    let result = search(SearchQuery::new(by_city).page_size(100)).await;
+    assert_eq!(result.total, 20);
+}
+
+#[tokio::test]
+async fn test_unknown_route_is_not_found() {
+    let err = mock()
+        .client()
+        .unwrap()
+        .get_listing("3001439887")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        HomegateError::UnexpectedStatus { status: 404, .. }
+    ));
+}
+
+#[test]
+fn test_invalid_search_body() {
+    let response = mock().handle("POST", "/search/listings", b"{}");
+    assert_eq!(response.status, 400);
+}
+
+#[tokio::test]
+async fn test_http_server() {
+    use homegate::api::request::HomegateClient;
+
+    let server = mock().start("127.0.0.1:0").await.unwrap();
+    let client = HomegateClient::builder()
+        .backend_url(server.uri())
+        .max_retries(0)
+        .build()
+        .unwrap();
+
+    let result = client
+        .search_with(&SearchQuery::new(zurich(1000)).page_size(5))
+        .await
+        .unwrap();
+    assert_eq!(result.total, 9);
+    assert_eq!(result.results.len(), 5);
+    assert_eq!(result.max_from, 5);
+}
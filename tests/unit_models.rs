@@ -7,11 +7,14 @@ mod tests {
     use homegate::models::address::Address;
     use homegate::models::geo_coords::GeoCoords;
     use homegate::models::listing::{
-        Attachment, Category, Characteristics, Currency, Lister, LocalizationEntryText, Price,
-        PriceInterval, Prices,
+        Attachment, AttachmentKind, Category, Characteristics, Currency, Lang, Lister,
+        Localization, LocalizationEntryText, Price, PriceInterval, Prices,
     };
+    use homegate::models::money::Money;
     use homegate::models::paginated::{parse_search_result, Paginated};
-    use homegate::models::realestate::{ListingType, ListingTypeWrapper, OfferType, RealEstate};
+    use homegate::models::realestate::{
+        group_by_lister, ListingType, ListingTypeWrapper, OfferType, PriceUnit, RealEstate,
+    };
     use std::fs;
 
     // ========== Category Enum Tests (37 variants) ==========
@@ -239,13 +242,15 @@ mod tests {
     #[test]
     fn test_characteristics_serialization() {
         let chars = Characteristics {
-            living_space: 80,
-            number_of_rooms: 3.5,
+            living_space: Some(80),
+            number_of_rooms: Some(3.5),
+            ..Characteristics::default()
         };
 
         let json = serde_json::to_string(&chars).unwrap();
         assert!(json.contains("livingSpace"));
         assert!(json.contains("numberOfRooms"));
+        assert!(!json.contains("lotSize"));
     }
 
     #[test]
@@ -253,14 +258,64 @@ mod tests {
         let json = r#"{"livingSpace":80,"numberOfRooms":3.5}"#;
         let chars: Characteristics = serde_json::from_str(json).unwrap();
 
-        assert_eq!(chars.living_space, 80);
-        assert_eq!(chars.number_of_rooms, 3.5);
+        assert_eq!(chars.living_space, Some(80));
+        assert_eq!(chars.number_of_rooms, Some(3.5));
+    }
+
+    #[test]
+    fn test_characteristics_all_fields() {
+        let json = r#"{
+            "livingSpace": 180,
+            "numberOfRooms": 6.5,
+            "floor": -1,
+            "numberOfFloors": 3,
+            "lotSize": 640,
+            "singleFloorSpace": 60,
+            "totalFloorSpace": 200,
+            "yearBuilt": 1975,
+            "yearLastRenovated": 2015,
+            "hasBalcony": true,
+            "hasElevator": false,
+            "hasParking": true,
+            "hasGarage": true,
+            "arePetsAllowed": true,
+            "isWheelchairAccessible": false,
+            "isNewBuilding": false,
+            "hasFireplace": true
+        }"#;
+        let chars: Characteristics = serde_json::from_str(json).unwrap();
+
+        assert_eq!(chars.floor, Some(-1));
+        assert_eq!(chars.lot_size, Some(640));
+        assert_eq!(chars.single_floor_space, Some(60));
+        assert_eq!(chars.total_floor_space, Some(200));
+        assert_eq!(chars.year_built, Some(1975));
+        assert_eq!(chars.year_last_renovated, Some(2015));
+        assert_eq!(chars.has_parking, Some(true));
+        assert_eq!(chars.is_wheelchair_accessible, Some(false));
+
+        let round_trip: Characteristics =
+            serde_json::from_str(&serde_json::to_string(&chars).unwrap()).unwrap();
+        assert_eq!(round_trip, chars);
+    }
+
+    #[test]
+    fn test_characteristics_of_storage_room() {
+        // Storage rooms and parking spaces have neither rooms nor living space
+        let chars: Characteristics = serde_json::from_str(r#"{"totalFloorSpace":12}"#).unwrap();
+        assert_eq!(chars.living_space, None);
+        assert_eq!(chars.number_of_rooms, None);
+        assert_eq!(chars.total_floor_space, Some(12));
+
+        let empty: Characteristics = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, Characteristics::default());
     }
 
     #[test]
     fn test_lister_with_phone() {
         let lister = Lister {
             phone: Some("+41 44 123 45 67".to_string()),
+            ..Lister::default()
         };
 
         let json = serde_json::to_string(&lister).unwrap();
@@ -269,7 +324,10 @@ mod tests {
 
     #[test]
     fn test_lister_without_phone() {
-        let lister = Lister { phone: None };
+        let lister = Lister {
+            phone: None,
+            ..Lister::default()
+        };
 
         let json = serde_json::to_string(&lister).unwrap();
         let deserialized: Lister = serde_json::from_str(&json).unwrap();
@@ -279,9 +337,10 @@ mod tests {
     #[test]
     fn test_attachment_structure() {
         let attachment = Attachment {
-            t: "IMAGE".to_string(),
+            t: AttachmentKind::Image,
             url: "https://example.com/image.jpg".to_string(),
             file: "image.jpg".to_string(),
+            title: None,
         };
 
         let json = serde_json::to_string(&attachment).unwrap();
@@ -294,6 +353,7 @@ mod tests {
     fn test_localization_entry_text() {
         let text = LocalizationEntryText {
             title: "Beautiful Apartment".to_string(),
+            description: None,
         };
 
         let json = serde_json::to_string(&text).unwrap();
@@ -321,6 +381,7 @@ mod tests {
             net: Some(2000),
             gross: Some(2200),
             extra: Some(200),
+            unit: None,
         };
 
         let json = serde_json::to_string(&price).unwrap();
@@ -337,6 +398,7 @@ mod tests {
             net: None,
             gross: Some(2200),
             extra: None,
+            unit: None,
         };
 
         let json = serde_json::to_string(&price).unwrap();
@@ -354,6 +416,7 @@ mod tests {
                 net: Some(2000),
                 gross: Some(2200),
                 extra: Some(200),
+                unit: None,
             }),
             currency: Currency::CHF,
             buy: None,
@@ -364,6 +427,25 @@ mod tests {
         assert!(json.contains("currency"));
     }
 
+    #[test]
+    fn test_prices_for_offer_type() {
+        let prices = Prices {
+            rent: None,
+            currency: Currency::CHF,
+            buy: Some(Price {
+                interval: None,
+                net: None,
+                gross: Some(1_250_000),
+                extra: None,
+                unit: None,
+            }),
+        };
+
+        assert!(prices.for_offer_type(&OfferType::RENT).is_none());
+        let buy = prices.for_offer_type(&OfferType::BUY).unwrap();
+        assert_eq!(buy.amount(), Some(1_250_000));
+    }
+
     #[test]
     fn test_address_with_all_fields() {
         let address = Address {
@@ -416,6 +498,162 @@ mod tests {
         assert!(json.contains("longitude"));
     }
 
+    fn rent(json: &str) -> Prices {
+        serde_json::from_str(&format!(r#"{{"rent":{},"currency":"CHF"}}"#, json)).unwrap()
+    }
+
+    #[test]
+    fn test_normalized_monthly_gross_intervals() {
+        let monthly = rent(r#"{"interval":"MONTH","net":2585,"gross":2785,"extra":200}"#);
+        assert_eq!(
+            monthly.normalized_monthly_gross(None),
+            Some(Money::new(2785, Currency::CHF))
+        );
+
+        // 52 weeks per year: 600 * 52 / 12 = 2600
+        let weekly = rent(r#"{"interval":"WEEK","gross":600}"#);
+        assert_eq!(
+            weekly.normalized_monthly_gross(None),
+            Some(Money::new(2600, Currency::CHF))
+        );
+        let weekly_unit = rent(r#"{"gross":600,"unit":"WEEKLY"}"#);
+        assert_eq!(
+            weekly_unit.normalized_monthly_gross(None),
+            Some(Money::new(2600, Currency::CHF))
+        );
+
+        let yearly = rent(r#"{"interval":"YEAR","gross":1000}"#);
+        assert_eq!(
+            yearly.normalized_monthly_gross(None),
+            Some(Money::from_cents(8333, Currency::CHF))
+        );
+
+        // Net plus extra costs when the gross price is missing
+        let net = rent(r#"{"interval":"MONTH","net":2350,"extra":150}"#);
+        assert_eq!(
+            net.normalized_monthly_gross(None),
+            Some(Money::new(2500, Currency::CHF))
+        );
+        assert!(rent(r#"{"interval":"MONTH","net":2350}"#)
+            .normalized_monthly_gross(None)
+            .is_none());
+
+        assert!(rent(r#"{"interval":"FORTNIGHT","gross":600}"#)
+            .normalized_monthly_gross(None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_normalized_monthly_gross_per_m2_yearly() {
+        // Commercial space: CHF 240 per m² per year for 150 m² = CHF 3000 per month
+        let prices = rent(r#"{"interval":"YEAR","gross":240,"unit":"M2YEARLY"}"#);
+        assert_eq!(
+            prices.normalized_monthly_gross(Some(150)),
+            Some(Money::new(3000, Currency::CHF))
+        );
+        assert_eq!(
+            prices.monthly_gross_per_m2(Some(150)),
+            Some(Money::new(20, Currency::CHF))
+        );
+        assert!(prices.normalized_monthly_gross(None).is_none());
+        assert!(prices.normalized_monthly_gross(Some(0)).is_none());
+    }
+
+    #[test]
+    fn test_normalized_monthly_gross_per_m2_yearly_net_and_extra() {
+        // CHF 240 per m² per year for 150 m² = CHF 3000, plus CHF 300 extra costs per month
+        let monthly = rent(r#"{"interval":"MONTH","net":240,"extra":300,"unit":"M2YEARLY"}"#);
+        assert_eq!(monthly.rent.as_ref().unwrap().gross_amount(), None);
+        assert_eq!(
+            monthly.normalized_monthly_net(Some(150)),
+            Some(Money::new(3000, Currency::CHF))
+        );
+        assert_eq!(
+            monthly.normalized_monthly_gross(Some(150)),
+            Some(Money::new(3300, Currency::CHF))
+        );
+
+        // Yearly extra costs: CHF 1200 per year = CHF 100 per month
+        let yearly = rent(r#"{"interval":"YEAR","net":240,"extra":1200,"unit":"M2YEARLY"}"#);
+        assert_eq!(
+            yearly.normalized_monthly_gross(Some(150)),
+            Some(Money::new(3100, Currency::CHF))
+        );
+        assert!(yearly.normalized_monthly_gross(None).is_none());
+        assert!(rent(r#"{"interval":"MONTH","net":240,"unit":"M2YEARLY"}"#)
+            .normalized_monthly_gross(Some(150))
+            .is_none());
+    }
+
+    #[test]
+    fn test_normalized_monthly_net() {
+        // 52 weeks per year: 500 * 52 / 12 = 2166.67
+        let weekly = rent(r#"{"interval":"WEEK","net":500}"#);
+        assert_eq!(
+            weekly.normalized_monthly_net(None),
+            Some(Money::from_cents(216_667, Currency::CHF))
+        );
+        assert!(weekly.normalized_monthly_gross(None).is_none());
+    }
+
+    #[test]
+    fn test_purchase_price_per_m2() {
+        let prices: Prices =
+            serde_json::from_str(r#"{"buy":{"gross":1250000},"currency":"CHF"}"#).unwrap();
+        assert_eq!(
+            prices.purchase_price(),
+            Some(Money::new(1_250_000, Currency::CHF))
+        );
+        assert_eq!(
+            prices.purchase_price_per_m2(Some(125)),
+            Some(Money::new(10_000, Currency::CHF))
+        );
+        assert!(prices.normalized_monthly_gross(Some(125)).is_none());
+    }
+
+    #[test]
+    fn test_listing_price_helpers() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let listing = &paginated.results[0].listing;
+
+        let monthly = listing.normalized_monthly_gross().unwrap();
+        assert_eq!(monthly, Money::new(2785, Currency::CHF));
+        let area = u64::from(listing.characteristics.floor_area().unwrap());
+        assert_eq!(
+            listing.price_per_m2().unwrap().cents,
+            (278_500 + area / 2) / area
+        );
+    }
+
+    #[test]
+    fn test_money_ordering_and_display() {
+        let a = Money::new(1500, Currency::CHF);
+        let b = Money::from_cents(150_050, Currency::CHF);
+        let other = Money::new(1500, Currency::Unknown("EUR".to_string()));
+
+        assert!(a < b);
+        assert_eq!(b.to_string(), "CHF 1500.50");
+        assert_eq!(b.rounded(), 1501);
+        assert_eq!(b.amount(), 1500.5);
+        assert!(a.partial_cmp(&other).is_none());
+        assert!(a.checked_add(&other).is_none());
+        assert_ne!(a, other);
+    }
+
+    #[test]
+    fn test_money_overflow() {
+        let max = Money::from_cents(u64::MAX, Currency::CHF);
+        let one = Money::from_cents(1, Currency::CHF);
+
+        assert!(max.checked_add(&one).is_none());
+        assert_eq!(max.rounded(), u64::MAX / 100);
+        assert_eq!(
+            one.checked_add(&one),
+            Some(Money::from_cents(2, Currency::CHF))
+        );
+    }
+
     // ========== RealEstate Tests ==========
 
     #[test]
@@ -425,6 +663,14 @@ mod tests {
         assert_eq!(json, r#""RENT""#);
     }
 
+    #[test]
+    fn test_offer_type_buy() {
+        let offer = OfferType::BUY;
+        let json = serde_json::to_string(&offer).unwrap();
+        assert_eq!(json, r#""BUY""#);
+        assert_eq!("buy".parse::<OfferType>(), Ok(OfferType::BUY));
+    }
+
     #[test]
     fn test_listing_type_premium() {
         let lt = ListingType::PREMIUM;
@@ -456,6 +702,170 @@ mod tests {
         assert!(json.contains("type"));
     }
 
+    #[test]
+    fn test_real_estate_placement_and_branding() {
+        let file = fs::read_to_string("./resources/test/result-1.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let real_estate = &paginated.results[0];
+
+        assert_eq!(real_estate.listing_type(), Some(&ListingType::STANDARD));
+        assert!(!real_estate.is_promoted());
+        assert!(!real_estate.remote_viewing);
+
+        let branding = real_estate.lister_branding.as_ref().unwrap();
+        assert_eq!(
+            branding.legal_name.as_deref(),
+            Some("Apleona Real Estate AG")
+        );
+        assert!(branding.logo_url.as_ref().unwrap().ends_with(".png"));
+        assert!(!branding.is_quality_partner);
+        let address = branding.address.as_ref().unwrap();
+        assert_eq!(address.postal_code.as_deref(), Some("8304"));
+        assert_eq!(address.street_addition.as_deref(), Some("Postfach"));
+    }
+
+    #[test]
+    fn test_real_estate_promoted_listing() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let real_estate = &paginated.results[0];
+
+        assert_eq!(real_estate.listing_type(), Some(&ListingType::TOP));
+        assert!(real_estate.is_promoted());
+        assert!(real_estate.lister_branding.is_none());
+    }
+
+    #[test]
+    fn test_real_estate_optional_fields_default() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&file).unwrap();
+        let item = json["results"][0].as_object_mut().unwrap();
+        item.remove("listingType");
+        item.insert("remoteViewing".to_string(), serde_json::json!(true));
+
+        let real_estate: RealEstate = serde_json::from_value(json["results"][0].clone()).unwrap();
+        assert!(real_estate.listing_type().is_none());
+        assert!(!real_estate.is_promoted());
+        assert!(real_estate.remote_viewing);
+
+        let round_trip = serde_json::to_value(&real_estate).unwrap();
+        assert_eq!(round_trip["remoteViewing"], true);
+        assert!(round_trip.get("listingType").is_none());
+    }
+
+    #[test]
+    fn test_lister_full_deserialization() {
+        let json = r#"{
+            "id": "x501m",
+            "name": "Apleona Real Estate AG",
+            "phone": "+41 44 878 78 22",
+            "logoUrl": "https://media.homegate.ch/v1/neutral/img/logos/l_x501m.png",
+            "address": {"street": "Hertistrasse 2a", "postalCode": "8304", "locality": "Wallisellen"},
+            "websiteUrl": "https://www.apleona.com"
+        }"#;
+        let lister: Lister = serde_json::from_str(json).unwrap();
+        assert_eq!(lister.id.as_deref(), Some("x501m"));
+        assert_eq!(lister.name.as_deref(), Some("Apleona Real Estate AG"));
+        assert_eq!(lister.website.as_deref(), Some("https://www.apleona.com"));
+        let address = lister.address.as_ref().unwrap();
+        assert_eq!(address.locality.as_deref(), Some("Wallisellen"));
+        assert_eq!(lister.key().as_deref(), Some("id:x501m"));
+    }
+
+    #[test]
+    fn test_lister_key_fallbacks() {
+        assert_eq!(Lister::default().key(), None);
+
+        let by_phone = Lister {
+            phone: Some("+41 44 878 78 22".to_string()),
+            ..Lister::default()
+        };
+        assert_eq!(by_phone.key().as_deref(), Some("phone:41448787822"));
+
+        let by_name = Lister {
+            name: Some("Livit AG".to_string()),
+            ..by_phone.clone()
+        };
+        assert_eq!(by_name.key().as_deref(), Some("name:livit ag"));
+
+        let blank_name = Lister {
+            name: Some("  ".to_string()),
+            ..by_phone
+        };
+        assert_eq!(blank_name.key().as_deref(), Some("phone:41448787822"));
+    }
+
+    #[test]
+    fn test_real_estate_lister_merges_branding() {
+        let file = fs::read_to_string("./resources/test/result-1.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let lister = paginated.results[0].lister();
+
+        assert_eq!(lister.name.as_deref(), Some("Apleona Real Estate AG"));
+        assert_eq!(lister.phone.as_deref(), Some("+41 44 878 78 22"));
+        assert!(lister.logo_url.as_ref().unwrap().ends_with("l_x501m.png"));
+        let address = lister.address.as_ref().unwrap();
+        assert_eq!(address.postal_code.as_deref(), Some("8304"));
+    }
+
+    #[test]
+    fn test_group_by_lister() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let groups = group_by_lister(&paginated.results);
+
+        let total: usize = groups.iter().map(|g| g.listings.len()).sum();
+        assert_eq!(total, paginated.results.len());
+
+        let livit = &groups[0];
+        assert_eq!(livit.lister.name.as_deref(), Some("Livit AG"));
+        assert_eq!(livit.key.as_deref(), Some("name:livit ag"));
+        let ids: Vec<&str> = livit.listings.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["3001616464", "3001640368", "3001610226"]);
+
+        let apleona = &groups[1];
+        assert_eq!(
+            apleona.lister.name.as_deref(),
+            Some("Apleona Real Estate AG")
+        );
+        assert_eq!(apleona.listings.len(), 2);
+
+        let unknown = groups.last().unwrap();
+        assert!(unknown.key.is_none());
+        assert_eq!(unknown.listings.len(), 3);
+    }
+
+    #[test]
+    fn test_attachment_kind_deserialization() {
+        let json = r#"[{"type":"FLOOR_PLAN","url":"https://example.com/plan.pdf","file":"plan.pdf","title":"Grundriss"},
+                       {"type":"PANORAMA","url":"https://example.com/360.jpg","file":"360.jpg"}]"#;
+        let attachments: Vec<Attachment> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(attachments[0].t, AttachmentKind::FloorPlan);
+        assert_eq!(attachments[0].title.as_deref(), Some("Grundriss"));
+        assert_eq!(
+            attachments[1].t,
+            AttachmentKind::Unknown("PANORAMA".to_string())
+        );
+        assert_eq!(AttachmentKind::VirtualTour.as_str(), "VIRTUAL_TOUR");
+    }
+
+    #[test]
+    fn test_listing_images_across_languages() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let paginated: Paginated<RealEstate> = serde_json::from_str(&file).unwrap();
+        let listing = &paginated.results[0].listing;
+
+        let images = listing.images();
+        assert!(!images.is_empty());
+        assert!(images.iter().all(|a| a.t == AttachmentKind::Image));
+        assert!(listing.floor_plans().is_empty());
+
+        let mut urls: Vec<&str> = images.iter().map(|a| a.url.as_str()).collect();
+        urls.dedup();
+        assert_eq!(urls.len(), images.len());
+    }
+
     // ========== Paginated Tests ==========
 
     #[test]
@@ -467,6 +877,60 @@ mod tests {
         assert!(paginated_result.total > 0);
     }
 
+    #[test]
+    fn test_parse_result_lenient_skips_malformed_listings() {
+        use homegate::models::paginated::parse_search_result_lenient;
+
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&file).unwrap();
+        let count = json["results"].as_array().unwrap().len();
+        let broken_id = json["results"][1]["id"].as_str().unwrap().to_string();
+        json["results"][1]["listing"]["characteristics"]["livingSpace"] =
+            serde_json::json!("sixty");
+        json["results"][2]["listing"]
+            .as_object_mut()
+            .unwrap()
+            .remove("address");
+        let json = json.to_string();
+
+        assert!(parse_search_result(&json).is_err());
+
+        let page = parse_search_result_lenient(&json).unwrap();
+        assert_eq!(page.results.len(), count - 2);
+        assert_eq!(page.skipped.len(), 2);
+
+        let first = &page.skipped[0];
+        assert_eq!(first.index, 1);
+        assert_eq!(first.id.as_deref(), Some(broken_id.as_str()));
+        assert_eq!(first.path, "results[1].listing.characteristics.livingSpace");
+        assert!(first.to_string().contains(&broken_id));
+
+        assert_eq!(page.skipped[1].path, "results[2].listing");
+        assert!(page.skipped[1].message.contains("address"));
+    }
+
+    #[test]
+    fn test_parse_result_lenient_matches_strict_on_valid_page() {
+        use homegate::models::paginated::parse_search_result_lenient;
+
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let strict = parse_search_result(&file).unwrap();
+        let lenient = parse_search_result_lenient(&file).unwrap();
+
+        assert!(lenient.skipped.is_empty());
+        assert_eq!(lenient.results.len(), strict.results.len());
+        assert_eq!(lenient.total, strict.total);
+        assert_eq!(lenient.max_from, strict.max_from);
+    }
+
+    #[test]
+    fn test_parse_result_lenient_rejects_malformed_page() {
+        use homegate::models::paginated::parse_search_result_lenient;
+
+        assert!(parse_search_result_lenient("not json").is_err());
+        assert!(parse_search_result_lenient(r#"{"results":[]}"#).is_err());
+    }
+
     #[test]
     fn test_paginated_structure() {
         let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
@@ -554,4 +1018,232 @@ mod tests {
             assert!(!result.listing.localization.primary.is_empty());
         }
     }
+
+    fn localization(json: &str) -> Localization {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn entry(title: &str) -> String {
+        format!(r#"{{"attachments":[],"text":{{"title":"{}"}}}}"#, title)
+    }
+
+    #[test]
+    fn test_lang_from_str() {
+        assert_eq!("fr".parse::<Lang>(), Ok(Lang::Fr));
+        assert_eq!(" IT ".parse::<Lang>(), Ok(Lang::It));
+        assert!("rm".parse::<Lang>().is_err());
+        for lang in Lang::ALL {
+            assert_eq!(lang.to_string().parse::<Lang>(), Ok(lang));
+        }
+    }
+
+    #[test]
+    fn test_localization_resolve_prefers_requested_language() {
+        let loc = localization(&format!(
+            r#"{{"de":{},"fr":{},"primary":"de"}}"#,
+            entry("Wohnung"),
+            entry("Appartement")
+        ));
+
+        assert_eq!(loc.resolve(&[Lang::Fr]).unwrap().text.title, "Appartement");
+        assert_eq!(
+            loc.resolve(&[Lang::En, Lang::Fr]).unwrap().text.title,
+            "Appartement"
+        );
+        assert_eq!(loc.resolve(&[Lang::It]).unwrap().text.title, "Wohnung");
+        assert_eq!(loc.resolve(&[]).unwrap().text.title, "Wohnung");
+    }
+
+    #[test]
+    fn test_localization_resolve_falls_back_to_primary() {
+        let loc = localization(&format!(
+            r#"{{"fr":{},"it":{},"primary":"it"}}"#,
+            entry("Appartement"),
+            entry("Appartamento")
+        ));
+
+        assert_eq!(loc.primary_lang(), Some(Lang::It));
+        assert!(loc.get(Lang::De).is_none());
+        assert_eq!(loc.resolve(&[Lang::De]).unwrap().text.title, "Appartamento");
+    }
+
+    #[test]
+    fn test_localization_resolve_without_primary_entry() {
+        // The primary language is missing, any available entry is used
+        let loc = localization(&format!(r#"{{"en":{},"primary":"rm"}}"#, entry("Flat")));
+        assert_eq!(loc.primary_lang(), None);
+        assert_eq!(loc.resolve(&[Lang::De]).unwrap().text.title, "Flat");
+
+        let empty = localization(r#"{"primary":"de"}"#);
+        assert!(empty.resolve(&Lang::ALL).is_none());
+    }
+
+    // ========== Forward Compatibility Tests ==========
+
+    #[test]
+    fn test_unknown_enum_values_round_trip() {
+        let category: Category = serde_json::from_str(r#""TREE_HOUSE""#).unwrap();
+        assert_eq!(category, Category::Unknown("TREE_HOUSE".to_string()));
+        assert!(category.is_unknown());
+        assert_eq!(category.to_string(), "TREE_HOUSE");
+        assert_eq!(serde_json::to_string(&category).unwrap(), r#""TREE_HOUSE""#);
+
+        let interval: PriceInterval = serde_json::from_str(r#""DAY""#).unwrap();
+        assert_eq!(interval, PriceInterval::Unknown("DAY".to_string()));
+        assert_eq!(serde_json::to_string(&interval).unwrap(), r#""DAY""#);
+
+        let currency: Currency = serde_json::from_str(r#""EUR""#).unwrap();
+        assert_eq!(currency.as_str(), "EUR");
+
+        let offer_type: OfferType = serde_json::from_str(r#""AUCTION""#).unwrap();
+        assert_eq!(offer_type, OfferType::Unknown("AUCTION".to_string()));
+
+        let listing_type: ListingType = serde_json::from_str(r#""SPOTLIGHT""#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&listing_type).unwrap(),
+            r#""SPOTLIGHT""#
+        );
+
+        let unit: PriceUnit = serde_json::from_str(r#""DAILY""#).unwrap();
+        assert!(unit.is_unknown());
+    }
+
+    #[test]
+    fn test_known_enum_values_are_not_unknown() {
+        for category in Category::KNOWN {
+            let json = serde_json::to_string(category).unwrap();
+            let parsed: Category = serde_json::from_str(&json).unwrap();
+            assert_eq!(&parsed, category);
+            assert!(!parsed.is_unknown());
+        }
+        assert_eq!(PriceUnit::from_known("M2YEARLY"), Some(PriceUnit::M2YEARLY));
+        assert_eq!(ListingType::from_known("premium"), None);
+    }
+
+    #[test]
+    fn test_category_from_str_rejects_unknown() {
+        assert!("tree-house".parse::<Category>().is_err());
+        assert!("unknown".parse::<Category>().is_err());
+    }
+
+    #[test]
+    fn test_parse_result_with_unknown_values() {
+        let file = fs::read_to_string("./resources/test/result-2.json").unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&file).unwrap();
+        let listing = &mut json["results"][0]["listing"];
+        listing["categories"] = serde_json::json!(["APARTMENT", "TREE_HOUSE"]);
+        listing["offerType"] = serde_json::json!("AUCTION");
+        listing["prices"]["currency"] = serde_json::json!("EUR");
+        if let Some(rent) = listing["prices"]["rent"].as_object_mut() {
+            rent.insert("interval".to_string(), serde_json::json!("YEAR"));
+        }
+
+        let result = parse_search_result(&json.to_string()).unwrap();
+        let listing = &result.results[0].listing;
+        assert_eq!(
+            listing.categories,
+            vec![
+                Category::Apartment,
+                Category::Unknown("TREE_HOUSE".to_string())
+            ]
+        );
+        assert_eq!(
+            listing.offer_type,
+            OfferType::Unknown("AUCTION".to_string())
+        );
+        assert!(listing.prices.for_offer_type(&listing.offer_type).is_none());
+        assert_eq!(
+            listing.prices.currency,
+            Currency::Unknown("EUR".to_string())
+        );
+    }
+
+    // ========== ListingDetail Tests ==========
+
+    #[test]
+    fn test_listing_detail_deserialization() {
+        use homegate::models::listing_detail::ListingDetail;
+
+        let json = fs::read_to_string("./resources/test/listing-detail-1.json").unwrap();
+        let detail: ListingDetail = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(detail.offer_type, OfferType::RENT);
+        let chars = &detail.characteristics;
+        assert_eq!(chars.living_space, Some(60));
+        assert_eq!(chars.floor, Some(3));
+        assert_eq!(chars.year_built, Some(1890));
+        assert_eq!(chars.year_last_renovated, Some(2019));
+        assert_eq!(chars.has_elevator, Some(true));
+        assert_eq!(chars.are_pets_allowed, Some(false));
+        assert_eq!(detail.localization.entries().count(), 2);
+        assert!(detail.localization.fr.is_none());
+
+        // The living room image is shared between languages
+        let attachments = detail.attachments();
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[1].t, AttachmentKind::FloorPlan);
+        assert_eq!(attachments[0].title.as_deref(), Some("Wohnzimmer"));
+        assert_eq!(detail.images().len(), 1);
+        assert_eq!(detail.floor_plans().len(), 1);
+    }
+
+    #[test]
+    fn test_listing_detail_without_availability() {
+        use homegate::models::listing_detail::ListingDetail;
+
+        let mut json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string("./resources/test/listing-detail-1.json").unwrap(),
+        )
+        .unwrap();
+        let obj = json.as_object_mut().unwrap();
+        obj.remove("availableFrom");
+        obj.remove("features");
+
+        let detail: ListingDetail = serde_json::from_value(json).unwrap();
+        assert!(detail.available_from.is_none());
+        assert!(detail.features.is_empty());
+    }
+
+    #[test]
+    fn test_listing_detail_available_from_formats() {
+        use homegate::models::listing_detail::ListingDetail;
+
+        let json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string("./resources/test/listing-detail-1.json").unwrap(),
+        )
+        .unwrap();
+        let available_from = |value: serde_json::Value| {
+            let mut json = json.clone();
+            json["availableFrom"] = value;
+            serde_json::from_value::<ListingDetail>(json)
+                .unwrap()
+                .available_from
+        };
+
+        let april = chrono::NaiveDate::from_ymd_opt(2024, 4, 1);
+        assert_eq!(available_from("2024-04-01".into()), april);
+        assert_eq!(available_from("2024-04-01T00:00:00".into()), april);
+        assert_eq!(available_from("2024-04-01T00:00:00+02:00".into()), april);
+        assert_eq!(available_from("immediately".into()), None);
+        assert_eq!(available_from(serde_json::Value::Null), None);
+    }
+
+    #[test]
+    fn test_listing_detail_keeps_unknown_characteristics() {
+        use homegate::models::listing_detail::ListingDetail;
+
+        let mut json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string("./resources/test/listing-detail-1.json").unwrap(),
+        )
+        .unwrap();
+        json["characteristics"]["hasCableTv"] = true.into();
+
+        let detail: ListingDetail = serde_json::from_value(json).unwrap();
+        assert_eq!(detail.characteristics.floor, Some(3));
+        assert_eq!(detail.characteristics.extra["hasCableTv"], true);
+
+        let serialized = serde_json::to_value(&detail).unwrap();
+        assert_eq!(serialized["characteristics"]["hasCableTv"], true);
+        assert_eq!(serialized["availableFrom"], "2024-04-01");
+    }
 }
@@ -0,0 +1,181 @@
+/// Unit tests for rate_limit module
+///
+/// Tests token bucket throttling, the daily budget and its persistence
+use homegate::api::rate_limit::{RateLimitError, RateLimiter};
+use homegate::config::RateLimitConfig;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+// Helper function returning a fresh budget file path for a test
+fn budget_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join(format!("homegate-test-{}", std::process::id()))
+        .join(format!("{}.json", name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn budget_config(budget: u32, file: Option<PathBuf>) -> RateLimitConfig {
+    RateLimitConfig {
+        requests_per_second: 1000.0,
+        burst: 100,
+        daily_budget: Some(budget),
+        budget_file: file,
+    }
+}
+
+#[tokio::test]
+async fn test_burst_is_not_throttled() {
+    let limiter = RateLimiter::new(&RateLimitConfig {
+        requests_per_second: 1.0,
+        burst: 3,
+        ..RateLimitConfig::default()
+    })
+    .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.acquire().await.unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_requests_beyond_burst_wait() {
+    let limiter = RateLimiter::new(&RateLimitConfig {
+        requests_per_second: 20.0,
+        burst: 1,
+        ..RateLimitConfig::default()
+    })
+    .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.acquire().await.unwrap();
+    }
+    // 3 requests beyond the burst at 50ms each
+    assert!(start.elapsed() >= Duration::from_millis(140));
+}
+
+#[tokio::test]
+async fn test_clones_share_bucket() {
+    let limiter = RateLimiter::new(&RateLimitConfig {
+        requests_per_second: 20.0,
+        burst: 1,
+        ..RateLimitConfig::default()
+    })
+    .unwrap();
+    let clone = limiter.clone();
+
+    let start = Instant::now();
+    limiter.acquire().await.unwrap();
+    clone.acquire().await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(40));
+}
+
+#[tokio::test]
+async fn test_daily_budget_in_memory() {
+    let limiter = RateLimiter::new(&budget_config(2, None)).unwrap();
+
+    limiter.acquire().await.unwrap();
+    assert_eq!(limiter.remaining_budget().unwrap(), Some(1));
+    limiter.acquire().await.unwrap();
+
+    let err = limiter.acquire().await.unwrap_err();
+    assert!(matches!(err, RateLimitError::BudgetExhausted { budget: 2 }));
+    assert_eq!(limiter.remaining_budget().unwrap(), Some(0));
+}
+
+#[tokio::test]
+async fn test_daily_budget_shared_through_file() {
+    let path = budget_file("shared");
+    let first = RateLimiter::new(&budget_config(3, Some(path.clone()))).unwrap();
+    let second = RateLimiter::new(&budget_config(3, Some(path.clone()))).unwrap();
+
+    first.acquire().await.unwrap();
+    first.acquire().await.unwrap();
+    second.acquire().await.unwrap();
+
+    assert!(first.acquire().await.is_err());
+    assert!(second.acquire().await.is_err());
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("\"used\":3"));
+}
+
+#[tokio::test]
+async fn test_daily_budget_resets_on_new_day() {
+    let path = budget_file("stale");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, r#"{"date":"2000-01-01","used":5}"#).unwrap();
+
+    let limiter = RateLimiter::new(&budget_config(5, Some(path))).unwrap();
+    limiter.acquire().await.unwrap();
+    assert_eq!(limiter.remaining_budget().unwrap(), Some(4));
+}
+
+#[tokio::test]
+async fn test_daily_budget_rolls_over_with_clock() {
+    use chrono::TimeZone;
+    use homegate::clock::MockClock;
+    use std::sync::Arc;
+
+    let clock = MockClock::new(chrono::Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+    let limiter = RateLimiter::new(&budget_config(1, None))
+        .unwrap()
+        .clock(Arc::new(clock.clone()));
+
+    limiter.acquire().await.unwrap();
+    assert!(matches!(
+        limiter.acquire().await,
+        Err(RateLimitError::BudgetExhausted { budget: 1 })
+    ));
+
+    clock.advance(chrono::Duration::days(1));
+    assert_eq!(limiter.remaining_budget().unwrap(), Some(1));
+    limiter.acquire().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_daily_budget_ignores_corrupt_file() {
+    let path = budget_file("corrupt");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "not json").unwrap();
+
+    let limiter = RateLimiter::new(&budget_config(1, Some(path))).unwrap();
+    limiter.acquire().await.unwrap();
+    assert!(limiter.acquire().await.is_err());
+}
+
+#[test]
+fn test_invalid_config() {
+    let invalid = [
+        RateLimitConfig {
+            requests_per_second: 0.0,
+            ..RateLimitConfig::default()
+        },
+        RateLimitConfig {
+            requests_per_second: f64::NAN,
+            ..RateLimitConfig::default()
+        },
+        RateLimitConfig {
+            burst: 0,
+            ..RateLimitConfig::default()
+        },
+        RateLimitConfig {
+            daily_budget: Some(0),
+            ..RateLimitConfig::default()
+        },
+    ];
+
+    for config in invalid {
+        assert!(
+            matches!(
+                RateLimiter::new(&config),
+                Err(homegate::HomegateError::ValidationError(_))
+            ),
+            "{:?} should be rejected",
+            config
+        );
+    }
+}
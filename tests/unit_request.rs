@@ -203,3 +203,128 @@ async fn test_multiple_requests() {
         assert_eq!(response.status(), 200);
     }
 }
+
+#[tokio::test]
+async fn test_builder_uses_backend_url_and_auth_headers() {
+    use homegate::api::request::HomegateClient;
+    use wiremock::matchers::header_exists;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/test"))
+        .and(header_exists("authorization"))
+        .and(header_exists("x-app-id"))
+        .and(header_exists("x-app-version"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = HomegateClient::builder()
+        .backend_url(format!("{}/", mock_server.uri()))
+        .build()
+        .unwrap();
+    assert_eq!(client.backend_url(), mock_server.uri());
+
+    let url = client.endpoint("/test").unwrap();
+    let response = client.post_url(url, "{}").await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_builder_sends_extra_headers() {
+    use homegate::api::request::HomegateClient;
+    use wiremock::matchers::header;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/test"))
+        .and(header("x-debug", "1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = HomegateClient::builder()
+        .backend_url(mock_server.uri())
+        .header("X-Debug", "1")
+        .build()
+        .unwrap();
+
+    let url = client.endpoint("/test").unwrap();
+    let response = client.post_url(url, "{}").await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_from_config_with_injected_client() {
+    use homegate::api::request::HomegateClientBuilder;
+    use homegate::config::HomegateConfig;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/test"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = HomegateConfig {
+        backend_url: mock_server.uri(),
+        max_retries: 0,
+        ..HomegateConfig::default()
+    };
+    let client = HomegateClientBuilder::from_config(config)
+        .http_client(reqwest::Client::new())
+        .build()
+        .unwrap();
+
+    let url = client.endpoint("/test").unwrap();
+    let response = client.post_url(url, "{}").await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_timeout_from_config_is_applied() {
+    use homegate::api::request::HomegateClient;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/slow"))
+        .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(2)))
+        .mount(&mock_server)
+        .await;
+
+    let client = HomegateClient::builder()
+        .backend_url(mock_server.uri())
+        .timeout(std::time::Duration::from_millis(100))
+        .max_retries(0)
+        .build()
+        .unwrap();
+
+    let url = client.endpoint("/slow").unwrap();
+    assert!(client.post_url(url, "{}").await.is_err());
+}
+
+#[test]
+fn test_builder_rejects_invalid_configuration() {
+    use homegate::api::request::HomegateClient;
+
+    assert!(HomegateClient::builder()
+        .backend_url("not a valid url")
+        .build()
+        .is_err());
+    assert!(HomegateClient::builder()
+        .header("Invalid Header", "value")
+        .build()
+        .is_err());
+    assert!(HomegateClient::builder()
+        .proxy("http://127.0.0.1:8080")
+        .http_client(reqwest::Client::new())
+        .build()
+        .is_err());
+}
@@ -1,7 +1,10 @@
 /// Unit tests for search module
 ///
 /// Tests search request creation, validation, and response handling
-use homegate::api::search::{default_search, FromTo, FromToFloat, Location, Query, SearchRequest};
+use homegate::api::search::{
+    default_search, FromTo, FromToFloat, Location, Query, SearchLocation, SearchQuery,
+    SearchRequest, SortBy, SortDirection,
+};
 use homegate::models::listing::Category;
 use homegate::models::realestate::OfferType;
 use std::fs;
@@ -27,8 +30,8 @@ fn test_default_search_structure() {
     // Verify basic structure
     assert_eq!(req.from, 0);
     assert_eq!(req.size, 20);
-    assert_eq!(req.sort_by, "listingType");
-    assert_eq!(req.sort_direction, "desc");
+    assert_eq!(req.sort_by, SortBy::ListingType);
+    assert_eq!(req.sort_direction, SortDirection::Desc);
     assert!(req.track_total_hits);
 }
 
@@ -267,11 +270,11 @@ fn test_query_clone() {
             from: Some(50),
             to: None,
         },
-        location: Location {
+        location: SearchLocation::Radius(Location {
             latitude: 47.36667,
             longitude: 8.55,
             radius: 1000,
-        },
+        }),
         monthly_rent: FromTo {
             from: Some(500),
             to: Some(2000),
@@ -281,8 +284,227 @@ fn test_query_clone() {
             to: None,
         },
         offer_type: OfferType::RENT,
+        purchase_price: FromTo::default(),
     };
 
     let cloned = query.clone();
     assert_eq!(query, cloned);
 }
+
+fn zurich() -> Location {
+    Location {
+        latitude: ZURICH_LATLNG.0,
+        longitude: ZURICH_LATLNG.1,
+        radius: 1000,
+    }
+}
+
+#[test]
+fn test_search_query_has_no_hidden_filters() {
+    let req = SearchQuery::new(zurich()).to_request().unwrap();
+
+    assert_eq!(req.query.location, zurich().into());
+    assert_eq!(req.query.living_space.from, None);
+    assert_eq!(req.query.monthly_rent.from, None);
+    assert_eq!(req.query.number_of_rooms.from, None);
+    assert!(req.query.exclude_categories.is_empty());
+    assert!(req
+        .query
+        .categories
+        .contains(&Category::Apartment.to_string()));
+    assert_eq!(req.from, 0);
+    assert_eq!(req.size, 20);
+    assert_eq!(req.result_template, default_search().result_template);
+}
+
+#[test]
+fn test_search_query_sets_all_fields() {
+    let req = SearchQuery::new(zurich())
+        .categories([Category::Apartment, Category::Loft])
+        .exclude_categories([Category::FurnishedFlat])
+        .living_space(Some(50), Some(120))
+        .monthly_rent(Some(1000), Some(2500))
+        .number_of_rooms(Some(2.5), Some(4.5))
+        .offer_type(OfferType::RENT)
+        .sort(SortBy::Price, SortDirection::Asc)
+        .page(3)
+        .page_size(10)
+        .to_request()
+        .unwrap();
+
+    assert_eq!(req.query.categories, vec!["APARTMENT", "LOFT"]);
+    assert_eq!(req.query.exclude_categories, vec!["FURNISHED_FLAT"]);
+    assert_eq!(
+        req.query.living_space,
+        FromTo {
+            from: Some(50),
+            to: Some(120)
+        }
+    );
+    assert_eq!(req.query.monthly_rent.to, Some(2500));
+    assert_eq!(req.query.number_of_rooms.from, Some(2.5));
+    assert_eq!(req.sort_by, SortBy::Price);
+    assert_eq!(req.sort_direction, SortDirection::Asc);
+    assert_eq!(req.from, 20);
+    assert_eq!(req.size, 10);
+}
+
+#[test]
+fn test_search_query_reports_all_errors_at_once() {
+    let query = SearchQuery::new(Location {
+        latitude: 100.0,
+        longitude: 8.55,
+        radius: 1000,
+    })
+    .monthly_rent(Some(3000), Some(1000))
+    .number_of_rooms(Some(4.0), Some(2.0))
+    .page(0);
+
+    let err = query.validate().unwrap_err().to_string();
+    assert!(err.contains("location"));
+    assert!(err.contains("monthly_rent"));
+    assert!(err.contains("number_of_rooms"));
+    assert!(err.contains("page"));
+    assert!(query.to_request().is_err());
+}
+
+#[test]
+fn test_search_query_rejects_page_beyond_i32_range() {
+    let query = SearchQuery::new(zurich()).page(u32::MAX).page_size(100);
+
+    let err = query.validate().unwrap_err().to_string();
+    assert!(err.contains("page: 4294967295 is too large"));
+    assert!(query.to_request().is_err());
+
+    // The last page whose first index still fits in an i32
+    let last = (i32::MAX as u32) / 100 + 1;
+    let request = SearchQuery::new(zurich())
+        .page(last)
+        .page_size(100)
+        .to_request()
+        .unwrap();
+    assert_eq!(request.from, (last as i32 - 1) * 100);
+    assert!(SearchQuery::new(zurich())
+        .page(last + 1)
+        .page_size(100)
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn test_search_query_rejects_conflicting_categories() {
+    let query = SearchQuery::new(zurich())
+        .categories([Category::Studio])
+        .exclude_categories([Category::Studio]);
+
+    assert!(query.validate().is_err());
+}
+
+#[test]
+fn test_category_from_str() {
+    assert_eq!("apartment".parse::<Category>(), Ok(Category::Apartment));
+    assert_eq!("attic-flat".parse::<Category>(), Ok(Category::AtticFlat));
+    assert_eq!(
+        "SINGLE_HOUSE".parse::<Category>(),
+        Ok(Category::SingleHouse)
+    );
+    assert!("spaceship".parse::<Category>().is_err());
+}
+
+#[test]
+fn test_search_query_buy_uses_purchase_price() {
+    let req = SearchQuery::new(zurich())
+        .offer_type(OfferType::BUY)
+        .price(Some(500_000), Some(1_000_000))
+        .to_request()
+        .unwrap();
+
+    assert_eq!(req.query.offer_type, OfferType::BUY);
+    assert_eq!(req.query.purchase_price.to, Some(1_000_000));
+    assert!(req.query.monthly_rent.is_empty());
+
+    let json = serde_json::to_value(&req).unwrap();
+    assert_eq!(json["query"]["offerType"], "BUY");
+    assert_eq!(json["query"]["purchasePrice"]["from"], 500_000);
+    assert!(json["query"].get("monthlyRent").is_none());
+}
+
+#[test]
+fn test_search_query_rejects_price_for_wrong_offer_type() {
+    let rent_with_purchase = SearchQuery::new(zurich()).purchase_price(Some(100_000), None);
+    assert!(rent_with_purchase.validate().is_err());
+
+    let buy_with_rent = SearchQuery::new(zurich())
+        .offer_type(OfferType::BUY)
+        .monthly_rent(Some(1000), None);
+    assert!(buy_with_rent.validate().is_err());
+}
+
+#[test]
+fn test_default_search_omits_purchase_price() {
+    let json = serde_json::to_value(default_search()).unwrap();
+    assert!(json["query"].get("purchasePrice").is_none());
+}
+
+#[test]
+fn test_sort_serialization() {
+    let req = SearchQuery::new(zurich())
+        .sort(SortBy::PublicationDate, SortDirection::Desc)
+        .to_request()
+        .unwrap();
+    let json = serde_json::to_value(&req).unwrap();
+
+    assert_eq!(json["sortBy"], "dateCreated");
+    assert_eq!(json["sortDirection"], "desc");
+    assert_eq!(SortBy::LivingSpace.to_string(), "livingSpace");
+    assert_eq!(SortDirection::Asc.to_string(), "asc");
+}
+
+#[test]
+fn test_sort_from_str() {
+    assert_eq!("newest".parse::<SortBy>(), Ok(SortBy::PublicationDate));
+    assert_eq!("relevance".parse::<SortBy>(), Ok(SortBy::ListingType));
+    assert_eq!("living-space".parse::<SortBy>(), Ok(SortBy::LivingSpace));
+    assert_eq!("Rooms".parse::<SortBy>(), Ok(SortBy::NumberOfRooms));
+    assert_eq!("dateCreated".parse::<SortBy>(), Ok(SortBy::PublicationDate));
+    assert!("popularity".parse::<SortBy>().is_err());
+
+    assert_eq!("DESC".parse::<SortDirection>(), Ok(SortDirection::Desc));
+    assert!("up".parse::<SortDirection>().is_err());
+}
+
+#[test]
+fn test_sort_default_direction() {
+    assert_eq!(
+        SortBy::PublicationDate.default_direction(),
+        SortDirection::Desc
+    );
+    assert_eq!(SortBy::Price.default_direction(), SortDirection::Asc);
+}
+
+#[test]
+fn test_search_location_geo_tags_serialization() {
+    let location = SearchLocation::GeoTags {
+        geo_tags: vec!["geo-city-winterthur".to_string()],
+    };
+    let json = serde_json::to_value(&location).unwrap();
+    assert_eq!(json["geoTags"][0], "geo-city-winterthur");
+
+    let deserialized: SearchLocation = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, location);
+    assert!(deserialized.as_radius().is_none());
+}
+
+#[test]
+fn test_search_location_radius_serialization_unchanged() {
+    let location: SearchLocation = zurich().into();
+    let json = serde_json::to_value(&location).unwrap();
+    assert_eq!(json["radius"], 1000);
+    assert_eq!(location.as_radius(), Some(&zurich()));
+}
+
+#[test]
+fn test_search_query_rejects_empty_geo_tags() {
+    let query = SearchQuery::new(SearchLocation::GeoTags { geo_tags: vec![] });
+    assert!(query.validate().is_err());
+}
@@ -0,0 +1,215 @@
+/// Unit tests for transport module
+///
+/// Tests the client against an in-memory transport, without an HTTP server
+use homegate::api::request::HomegateClient;
+use homegate::api::search::{Location, SearchQuery};
+use homegate::api::transport::Transport;
+use homegate::HomegateError;
+use reqwest::{Request, Response};
+use std::sync::{Arc, Mutex};
+
+/// A request as seen by the fake transport
+#[derive(Debug, Clone)]
+struct SentRequest {
+    method: String,
+    url: String,
+    authorization: Option<String>,
+    app_id: Option<String>,
+    body: Option<serde_json::Value>,
+}
+
+/// In-memory transport answering every request with a fixed response
+#[derive(Debug, Clone)]
+struct FakeTransport {
+    status: u16,
+    headers: Vec<(&'static str, &'static str)>,
+    body: String,
+    sent: Arc<Mutex<Vec<SentRequest>>>,
+}
+
+impl FakeTransport {
+    fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+            sent: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn sent(&self) -> Vec<SentRequest> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for FakeTransport {
+    async fn send(&self, request: Request) -> homegate::Result<Response> {
+        self.sent.lock().unwrap().push(SentRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            authorization: request
+                .headers()
+                .get("Authorization")
+                .map(|v| v.to_str().unwrap().to_string()),
+            app_id: request
+                .headers()
+                .get("X-App-Id")
+                .map(|v| v.to_str().unwrap().to_string()),
+            body: request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(|b| serde_json::from_slice(b).unwrap()),
+        });
+
+        let mut response = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            response = response.header(*name, *value);
+        }
+        Ok(Response::from(response.body(self.body.clone()).unwrap()))
+    }
+}
+
+/// Transport failing every request
+#[derive(Debug)]
+struct FailingTransport;
+
+#[async_trait::async_trait]
+impl Transport for FailingTransport {
+    async fn send(&self, _request: Request) -> homegate::Result<Response> {
+        Err(HomegateError::Middleware("connection refused".to_string()))
+    }
+}
+
+fn zurich() -> Location {
+    Location {
+        latitude: 47.36667,
+        longitude: 8.55,
+        radius: 1000,
+    }
+}
+
+#[tokio::test]
+async fn test_search_through_fake_transport() {
+    let fixture = std::fs::read_to_string("resources/test/result-2.json").unwrap();
+    let transport = FakeTransport::new(200, fixture);
+    let client = HomegateClient::builder()
+        .backend_url("https://fake.invalid/")
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    let query = SearchQuery::new(zurich()).page(2).page_size(10);
+    let result = client.search_with(&query).await.unwrap();
+    assert!(!result.results.is_empty());
+
+    let sent = transport.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].method, "POST");
+    assert_eq!(sent[0].url, "https://fake.invalid/search/listings");
+    assert!(sent[0]
+        .authorization
+        .as_deref()
+        .unwrap()
+        .starts_with("Basic "));
+    let body = sent[0].body.as_ref().unwrap();
+    assert_eq!(body["from"], 10);
+    assert_eq!(body["size"], 10);
+}
+
+#[tokio::test]
+async fn test_get_listing_through_fake_transport() {
+    let fixture = std::fs::read_to_string("resources/test/listing-detail-1.json").unwrap();
+    let transport = FakeTransport::new(200, fixture);
+    let client = HomegateClient::builder()
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    client.get_listing("3001439887").await.unwrap();
+
+    let sent = transport.sent();
+    assert_eq!(sent[0].method, "GET");
+    assert_eq!(sent[0].url, "https://api.homegate.ch/listings/3001439887");
+    assert!(sent[0].body.is_none());
+}
+
+#[tokio::test]
+async fn test_custom_transport_receives_app_id() {
+    use chrono::{TimeZone, Utc};
+    use homegate::api::app_id::AppIdentity;
+    use homegate::clock::MockClock;
+
+    let fixture = std::fs::read_to_string("resources/test/listing-detail-1.json").unwrap();
+    let transport = FakeTransport::new(200, fixture);
+    let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(now);
+    let identity = AppIdentity {
+        version: "13.0.0".to_string(),
+        ..AppIdentity::android()
+    };
+    let client = HomegateClient::builder()
+        .transport(transport.clone())
+        .identity(identity.clone())
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+
+    client.get_listing("3001439887").await.unwrap();
+    clock.advance(chrono::Duration::minutes(5));
+    client.get_listing("3001439887").await.unwrap();
+
+    let sent = transport.sent();
+    assert_eq!(
+        sent[0].app_id.as_deref(),
+        Some(identity.app_id(&now.naive_utc()).as_str())
+    );
+    let later = now + chrono::Duration::minutes(5);
+    assert_eq!(
+        sent[1].app_id.as_deref(),
+        Some(identity.app_id(&later.naive_utc()).as_str())
+    );
+}
+
+#[tokio::test]
+async fn test_fake_transport_status_is_classified() {
+    let mut transport = FakeTransport::new(429, "slow down");
+    transport.headers.push(("Retry-After", "5"));
+    let client = HomegateClient::builder()
+        .transport(transport)
+        .build()
+        .unwrap();
+
+    let err = client.search(&zurich()).await.unwrap_err();
+    assert!(matches!(err, HomegateError::RateLimited { .. }));
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(5)));
+}
+
+#[tokio::test]
+async fn test_transport_error_is_returned() {
+    let client = HomegateClient::builder()
+        .transport(FailingTransport)
+        .build()
+        .unwrap();
+
+    let err = client.search(&zurich()).await.unwrap_err();
+    assert!(matches!(err, HomegateError::Middleware(_)));
+}
+
+#[test]
+fn test_transport_conflicts_with_http_client_and_proxy() {
+    let with_client = HomegateClient::builder()
+        .transport(FailingTransport)
+        .http_client(reqwest::Client::new())
+        .build();
+    assert!(matches!(
+        with_client,
+        Err(HomegateError::ValidationError(_))
+    ));
+
+    let with_proxy = HomegateClient::builder()
+        .transport(FailingTransport)
+        .proxy("http://127.0.0.1:3128")
+        .build();
+    assert!(matches!(with_proxy, Err(HomegateError::ValidationError(_))));
+}